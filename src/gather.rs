@@ -0,0 +1,403 @@
+/// Copies the elements at a slice of indices out of a slice of primitive
+/// numbers, using the CPU's gather instructions when they are available.
+///
+/// This is implemented for `[f32]`, `[f64]`, `[i32]` and `[u32]`, indexed by
+/// `usize` or `u32`. The result is bit-identical to
+/// [`select_indices(indices).copied().collect()`](crate::SelectIndices::select_indices),
+/// including the payloads of NaNs, because the elements are only ever copied
+/// as integers.
+///
+/// On x86_64, AVX-512 and AVX2 support is detected at runtime, and a scalar
+/// loop is used on CPUs without either and on all other targets. The AVX-512
+/// path is only compiled with the `avx512` feature, which needs Rust 1.89.
+/// See [`GatherPath`] for choosing the instructions yourself.
+///
+/// ```rust
+/// use select_indices::prelude::*;
+///
+/// let data: Vec<f32> = (0..100).map(|i| i as f32 * 0.5).collect();
+///
+/// let gathered = data.gather_simd(&[90usize, 3, 47, 3]);
+///
+/// assert_eq!(gathered, [45.0, 1.5, 23.5, 1.5]);
+/// ```
+pub trait GatherSimd<Idx>
+{
+    /// The type of the gathered elements.
+    type Item;
+
+    /// Copies the elements at `indices`, in the same order as the indices,
+    /// with the fastest path returned by [`GatherPath::detect`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds, before anything is gathered.
+    fn gather_simd(&self, indices: &[Idx]) -> Vec<Self::Item>
+    {
+        self.gather_simd_with(indices, GatherPath::detect())
+    }
+
+    /// Copies the elements at `indices`, in the same order as the indices,
+    /// with the given path.
+    ///
+    /// Every path produces the same result. `u32` indices into a collection
+    /// with more than `i32::MAX + 1` elements always use the scalar path,
+    /// because the gather instructions treat 32-bit indices as signed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `path` is not supported by the CPU, or if any index is out
+    /// of bounds, before anything is gathered.
+    fn gather_simd_with(&self, indices: &[Idx], path: GatherPath) -> Vec<Self::Item>;
+}
+
+/// The instructions used by [`GatherSimd`] to gather elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GatherPath
+{
+    /// One element at a time, on any CPU.
+    Scalar,
+    /// AVX2 gather instructions, on x86_64.
+    Avx2,
+    /// AVX-512 gather instructions, on x86_64 with AVX-512F. Never supported
+    /// without the `avx512` feature.
+    Avx512,
+}
+
+impl GatherPath
+{
+    /// Returns the fastest path supported by the CPU.
+    pub fn detect() -> Self {
+        [GatherPath::Avx512, GatherPath::Avx2]
+            .iter()
+            .copied()
+            .find(|path| path.is_supported())
+            .unwrap_or(GatherPath::Scalar)
+    }
+
+    /// Returns `true` if the CPU supports this path.
+    pub fn is_supported(self) -> bool {
+        match self {
+            GatherPath::Scalar => true,
+            #[cfg(target_arch = "x86_64")]
+            GatherPath::Avx2 => is_x86_feature_detected!("avx2"),
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            GatherPath::Avx512 => is_x86_feature_detected!("avx512f"),
+            #[cfg(not(all(target_arch = "x86_64", feature = "avx512")))]
+            GatherPath::Avx512 => false,
+            #[cfg(not(target_arch = "x86_64"))]
+            GatherPath::Avx2 => false,
+        }
+    }
+}
+
+pub(crate) mod index {
+    /// An index type that [`GatherSimd`](crate::GatherSimd) accepts.
+    ///
+    /// This trait is sealed, and is implemented for `usize` and `u32`.
+    pub trait GatherIndex: Copy
+    {
+        fn to_usize(self) -> usize;
+
+        /// Copies the 32-bit elements at `indices` into `out`, with the
+        /// gather instructions of `path`.
+        ///
+        /// # Safety
+        ///
+        /// The CPU must support `path`, which must not be scalar, every index
+        /// must be in bounds of `data`, and `out` must have room for
+        /// `indices.len()` elements.
+        #[cfg(target_arch = "x86_64")]
+        unsafe fn gather32(path: super::GatherPath, data: &[u32], indices: &[Self], out: *mut u32);
+
+        /// Copies the 64-bit elements at `indices` into `out`, with the
+        /// gather instructions of `path`.
+        ///
+        /// # Safety
+        ///
+        /// Same as [`gather32`](GatherIndex::gather32).
+        #[cfg(target_arch = "x86_64")]
+        unsafe fn gather64(path: super::GatherPath, data: &[u64], indices: &[Self], out: *mut u64);
+
+        /// Returns true if every in bounds index of a collection of `len`
+        /// elements can be passed to the gather instructions.
+        #[cfg(target_arch = "x86_64")]
+        fn fits_gather(len: usize) -> bool;
+    }
+}
+use self::index::GatherIndex;
+
+impl GatherIndex for usize
+{
+    fn to_usize(self) -> usize {
+        self
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    unsafe fn gather32(path: GatherPath, data: &[u32], indices: &[Self], out: *mut u32) {
+        match path {
+            #[cfg(feature = "avx512")]
+            GatherPath::Avx512 => avx512::gather32_idx64(data, indices, out),
+            _ => avx2::gather32_idx64(data, indices, out),
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    unsafe fn gather64(path: GatherPath, data: &[u64], indices: &[Self], out: *mut u64) {
+        match path {
+            #[cfg(feature = "avx512")]
+            GatherPath::Avx512 => avx512::gather64_idx64(data, indices, out),
+            _ => avx2::gather64_idx64(data, indices, out),
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    fn fits_gather(_len: usize) -> bool {
+        // A slice never has more than isize::MAX bytes, so every in bounds
+        // index fits in the signed 64-bit lanes.
+        true
+    }
+}
+
+impl GatherIndex for u32
+{
+    fn to_usize(self) -> usize {
+        self as usize
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    unsafe fn gather32(path: GatherPath, data: &[u32], indices: &[Self], out: *mut u32) {
+        match path {
+            #[cfg(feature = "avx512")]
+            GatherPath::Avx512 => avx512::gather32_idx32(data, indices, out),
+            _ => avx2::gather32_idx32(data, indices, out),
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    unsafe fn gather64(path: GatherPath, data: &[u64], indices: &[Self], out: *mut u64) {
+        match path {
+            #[cfg(feature = "avx512")]
+            GatherPath::Avx512 => avx512::gather64_idx32(data, indices, out),
+            _ => avx2::gather64_idx32(data, indices, out),
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    fn fits_gather(len: usize) -> bool {
+        // The 32-bit lanes are signed, so indices past i32::MAX would be
+        // read as negative offsets.
+        len <= i32::MAX as usize + 1
+    }
+}
+
+/// Copies the elements at `indices` out of `data` with the given path. `T`
+/// must be one of the primitive numbers that [`GatherSimd`] is implemented for.
+fn gather<T, Idx>(data: &[T], indices: &[Idx], path: GatherPath) -> Vec<T>
+where
+    T: Copy,
+    Idx: GatherIndex,
+{
+    assert!(path.is_supported(), "gather_simd_with was passed {:?}, which this CPU does not support!", path);
+
+    let len = data.len();
+    for &index in indices {
+        let index = index.to_usize();
+        assert!(
+            index < len,
+            "gather_simd was passed an out of bounds index: the len is {} but the index is {}", len, index,
+        );
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if path != GatherPath::Scalar && Idx::fits_gather(len) {
+            let mut out: Vec<T> = Vec::with_capacity(indices.len());
+            // Safety: The CPU supports the path, every index was checked above,
+            // and the output has capacity for every index. The elements are
+            // plain numbers of the same size and alignment as the integers they
+            // are copied as.
+            unsafe {
+                match std::mem::size_of::<T>() {
+                    4 => Idx::gather32(path, std::slice::from_raw_parts(data.as_ptr().cast(), len), indices, out.as_mut_ptr().cast()),
+                    8 => Idx::gather64(path, std::slice::from_raw_parts(data.as_ptr().cast(), len), indices, out.as_mut_ptr().cast()),
+                    _ => unreachable!(),
+                }
+                out.set_len(indices.len());
+            }
+            return out;
+        }
+    }
+
+    gather_scalar(data, indices)
+}
+
+/// Copies the elements at `indices` out of `data` one at a time.
+fn gather_scalar<T, Idx>(data: &[T], indices: &[Idx]) -> Vec<T>
+where
+    T: Copy,
+    Idx: GatherIndex,
+{
+    indices.iter().map(|&index| data[index.to_usize()]).collect()
+}
+
+macro_rules! impl_gather_simd {
+    ($($t:ty),*) => {$(
+        impl<Idx: GatherIndex> GatherSimd<Idx> for [$t]
+        {
+            type Item = $t;
+
+            fn gather_simd_with(&self, indices: &[Idx], path: GatherPath) -> Vec<$t> {
+                gather(self, indices, path)
+            }
+        }
+    )*};
+}
+
+impl_gather_simd!(f32, f64, i32, u32);
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use std::arch::x86_64::*;
+
+    // Each kernel gathers as many full vectors as fit in `indices`, then
+    // copies the remainder one element at a time.
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn gather32_idx32(data: &[u32], indices: &[u32], out: *mut u32) {
+        let base: *const i32 = data.as_ptr().cast();
+        let mut chunks = indices.chunks_exact(8);
+        let mut out = out;
+        for chunk in &mut chunks {
+            let offsets = _mm256_loadu_si256(chunk.as_ptr().cast());
+            let values = _mm256_i32gather_epi32::<4>(base, offsets);
+            _mm256_storeu_si256(out.cast(), values);
+            out = out.add(8);
+        }
+        for (k, &index) in chunks.remainder().iter().enumerate() {
+            *out.add(k) = *data.get_unchecked(index as usize);
+        }
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn gather32_idx64(data: &[u32], indices: &[usize], out: *mut u32) {
+        let base: *const i32 = data.as_ptr().cast();
+        let mut chunks = indices.chunks_exact(4);
+        let mut out = out;
+        for chunk in &mut chunks {
+            let offsets = _mm256_loadu_si256(chunk.as_ptr().cast());
+            let values = _mm256_i64gather_epi32::<4>(base, offsets);
+            _mm_storeu_si128(out.cast(), values);
+            out = out.add(4);
+        }
+        for (k, &index) in chunks.remainder().iter().enumerate() {
+            *out.add(k) = *data.get_unchecked(index);
+        }
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn gather64_idx32(data: &[u64], indices: &[u32], out: *mut u64) {
+        let base: *const i64 = data.as_ptr().cast();
+        let mut chunks = indices.chunks_exact(4);
+        let mut out = out;
+        for chunk in &mut chunks {
+            let offsets = _mm_loadu_si128(chunk.as_ptr().cast());
+            let values = _mm256_i32gather_epi64::<8>(base, offsets);
+            _mm256_storeu_si256(out.cast(), values);
+            out = out.add(4);
+        }
+        for (k, &index) in chunks.remainder().iter().enumerate() {
+            *out.add(k) = *data.get_unchecked(index as usize);
+        }
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn gather64_idx64(data: &[u64], indices: &[usize], out: *mut u64) {
+        let base: *const i64 = data.as_ptr().cast();
+        let mut chunks = indices.chunks_exact(4);
+        let mut out = out;
+        for chunk in &mut chunks {
+            let offsets = _mm256_loadu_si256(chunk.as_ptr().cast());
+            let values = _mm256_i64gather_epi64::<8>(base, offsets);
+            _mm256_storeu_si256(out.cast(), values);
+            out = out.add(4);
+        }
+        for (k, &index) in chunks.remainder().iter().enumerate() {
+            *out.add(k) = *data.get_unchecked(index);
+        }
+    }
+}
+
+// The AVX-512 intrinsics were stabilised in Rust 1.89, past the crate's
+// minimum version, so they are only compiled with the `avx512` feature.
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+#[allow(clippy::incompatible_msrv)]
+mod avx512 {
+    use std::arch::x86_64::*;
+
+    // Same as the AVX2 kernels, with vectors twice as wide.
+
+    #[target_feature(enable = "avx512f")]
+    pub(super) unsafe fn gather32_idx32(data: &[u32], indices: &[u32], out: *mut u32) {
+        let base: *const i32 = data.as_ptr().cast();
+        let mut chunks = indices.chunks_exact(16);
+        let mut out = out;
+        for chunk in &mut chunks {
+            let offsets = _mm512_loadu_si512(chunk.as_ptr().cast());
+            let values = _mm512_i32gather_epi32::<4>(offsets, base);
+            _mm512_storeu_si512(out.cast(), values);
+            out = out.add(16);
+        }
+        for (k, &index) in chunks.remainder().iter().enumerate() {
+            *out.add(k) = *data.get_unchecked(index as usize);
+        }
+    }
+
+    #[target_feature(enable = "avx512f")]
+    pub(super) unsafe fn gather32_idx64(data: &[u32], indices: &[usize], out: *mut u32) {
+        let base: *const i32 = data.as_ptr().cast();
+        let mut chunks = indices.chunks_exact(8);
+        let mut out = out;
+        for chunk in &mut chunks {
+            let offsets = _mm512_loadu_si512(chunk.as_ptr().cast());
+            let values = _mm512_i64gather_epi32::<4>(offsets, base);
+            _mm256_storeu_si256(out.cast(), values);
+            out = out.add(8);
+        }
+        for (k, &index) in chunks.remainder().iter().enumerate() {
+            *out.add(k) = *data.get_unchecked(index);
+        }
+    }
+
+    #[target_feature(enable = "avx512f")]
+    pub(super) unsafe fn gather64_idx32(data: &[u64], indices: &[u32], out: *mut u64) {
+        let base: *const i64 = data.as_ptr().cast();
+        let mut chunks = indices.chunks_exact(8);
+        let mut out = out;
+        for chunk in &mut chunks {
+            let offsets = _mm256_loadu_si256(chunk.as_ptr().cast());
+            let values = _mm512_i32gather_epi64::<8>(offsets, base);
+            _mm512_storeu_si512(out.cast(), values);
+            out = out.add(8);
+        }
+        for (k, &index) in chunks.remainder().iter().enumerate() {
+            *out.add(k) = *data.get_unchecked(index as usize);
+        }
+    }
+
+    #[target_feature(enable = "avx512f")]
+    pub(super) unsafe fn gather64_idx64(data: &[u64], indices: &[usize], out: *mut u64) {
+        let base: *const i64 = data.as_ptr().cast();
+        let mut chunks = indices.chunks_exact(8);
+        let mut out = out;
+        for chunk in &mut chunks {
+            let offsets = _mm512_loadu_si512(chunk.as_ptr().cast());
+            let values = _mm512_i64gather_epi64::<8>(offsets, base);
+            _mm512_storeu_si512(out.cast(), values);
+            out = out.add(8);
+        }
+        for (k, &index) in chunks.remainder().iter().enumerate() {
+            *out.add(k) = *data.get_unchecked(index);
+        }
+    }
+}
\ No newline at end of file
@@ -1,6 +1,10 @@
-use std::marker::PhantomData;
+use std::{
+    marker::PhantomData,
+    vec::IntoIter,
+};
 use crate::indexed_type::{ Unindexed, Indexed };
 use crate::iter_type::Sequential;
+use crate::prefetch::{ PrefetchIndex, PrefetchIndices };
 #[cfg(feature = "rayon")]
 use crate::iter_type::Parallel;
 
@@ -28,6 +32,54 @@ impl<'a, Data, Indices, VisitedSet> SelectIndicesIter<'a, Data, Indices, Visited
     }
 }
 
+impl<'a, Data, Indices, IndexedType> SeqSelectIndicesIter<'a, Data, Indices, IndexedType>
+where
+    Data: ?Sized,
+    Indices: Iterator,
+    Indices::Item: Copy,
+{
+    /// Narrows the iterator down to the given positions of its remaining indices,
+    /// so that the `k`-th item is the item at index `remaining[sub_indices[k]]`.
+    pub fn select(self, sub_indices: &[usize]) -> SeqSelectIndicesIter<'a, Data, IntoIter<Indices::Item>, IndexedType>
+    {
+        let outer: Vec<Indices::Item> = self.indices.collect();
+        let indices: Vec<Indices::Item> = sub_indices.iter().map(|&k| outer[k]).collect();
+        SelectIndicesIter {
+            data: self.data,
+            indices: indices.into_iter(),
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Prefetches the element `distance` indices ahead of the one being produced,
+    /// so that it is more likely to be in the cache by the time it is reached.
+    /// 
+    /// This can speed up selections from collections much larger than the cache,
+    /// where most of the time is otherwise spent waiting on memory. On targets
+    /// other than x86_64 and aarch64, prefetching does nothing.
+    /// 
+    /// ```rust
+    /// use select_indices::prelude::*;
+    /// 
+    /// let data: Vec<u64> = (0..1000).collect();
+    /// let indices: Vec<usize> = (0..1000).map(|i| (i * 7) % 1000).collect();
+    /// 
+    /// let sum: u64 = data.select_indices(&indices).prefetch(8).sum();
+    /// 
+    /// assert_eq!(sum, data.iter().sum());
+    /// ```
+    pub fn prefetch(self, distance: usize) -> SeqSelectIndicesIter<'a, Data, PrefetchIndices<Data, Indices>, IndexedType>
+    where
+        Data: PrefetchIndex<Indices::Item>,
+    {
+        SelectIndicesIter {
+            data: self.data,
+            indices: PrefetchIndices::new(self.data, self.indices, distance),
+            _phantom: Default::default(),
+        }
+    }
+}
+
 /// Return type for [`select_indices`](crate::SelectIndices::select_indices).
 pub type SeqSelectIndicesIter<'a, Data, Indices, IndexedType> = SelectIndicesIter<'a, Data, Indices, Sequential, IndexedType>;
 #[cfg(feature = "rayon")]
@@ -2,6 +2,7 @@ use std::{
     ops::Index,
     slice::Iter,
     iter::Copied,
+    vec::IntoIter,
 };
 use crate::{
     indexed_type::Unindexed,
@@ -39,6 +40,60 @@ pub trait SelectIndices<'a> {
     {
         self.select_with_iter(indices.iter().copied())
     }
+
+    /// Iterate through a collection with an owned list of indices.
+    /// 
+    /// Unlike [`select_indices`](SelectIndices::select_indices), the returned
+    /// iterator only borrows the collection, so it can be returned from a function
+    /// that builds its own list of indices.
+    fn select_owned_indices<Idx>(&'a self, indices: Vec<Idx>) -> SeqSelectIndicesIter<'a, Self, IntoIter<Idx>, Unindexed>
+    where
+        Self: Index<Idx>,
+        Idx: Copy,
+    {
+        self.select_with_iter(indices)
+    }
+
+    /// Collect references to the elements at a slice of indices, in the same order
+    /// as the indices, while visiting the collection in ascending index order.
+    /// 
+    /// # Performance
+    /// 
+    /// When the indices are in a random order and the collection is large, most
+    /// of the time spent by [`select_indices`](SelectIndices::select_indices) is
+    /// spent waiting on cache and TLB misses. This method sorts a copy of the
+    /// indices first, so that nearby elements are fetched together, then places
+    /// each reference back at its original position. The sort and the buffer of
+    /// references cost time and memory of their own, so this is only worth it for
+    /// large collections and unordered indices.
+    /// 
+    /// ```rust
+    /// use select_indices::prelude::*;
+    /// 
+    /// let data: Vec<usize> = (0..100).map(|i| i * 2).collect();
+    /// 
+    /// let selected: Vec<&usize> = data.select_indices_localized(&[90, 3, 47, 3]).collect();
+    /// 
+    /// assert_eq!(selected, [&180, &6, &94, &6]);
+    /// ```
+    fn select_indices_localized<Idx>(&'a self, indices: &[Idx]) -> IntoIter<&'a <Self as Index<Idx>>::Output>
+    where
+        Self: Index<Idx>,
+        Idx: Copy + Ord,
+    {
+        let mut order: Vec<(Idx, usize)> = indices.iter().copied().zip(0..).collect();
+        order.sort_unstable();
+
+        let mut refs = Vec::with_capacity(indices.len());
+        let slots = refs.spare_capacity_mut();
+        for (index, position) in order {
+            slots[position].write(self.index(index));
+        }
+        // Safety: Every position from 0 to indices.len() appears exactly once in
+        // the sorted order, so every slot was written.
+        unsafe { refs.set_len(indices.len()) };
+        refs.into_iter()
+    }
 }
 
 
@@ -49,7 +104,10 @@ where
 
 #[cfg(feature = "rayon")]
 mod parallel {
-    use std::ops::Index;
+    use std::{
+        ops::Index,
+        mem::MaybeUninit,
+    };
     use crate::{
         indexed_type::Unindexed,
         immutable::iter::ParSelectIndicesIter,
@@ -58,7 +116,9 @@ mod parallel {
         prelude::*,
         slice::Iter,
         iter::Copied,
+        vec::IntoIter,
     };
+    use force_send_sync::Sync as ForceSync;
 
     /// Selectively iterate through a collection
     /// with a list of indices or an index iterator.
@@ -96,6 +156,43 @@ mod parallel {
         {
             self.par_select_with_iter(indices.into_par_iter().copied())
         }
+
+        /// Iterate through a collection with an owned list of indices.
+        /// 
+        /// Parallel form of [`select_owned_indices`](crate::SelectIndices::select_owned_indices).
+        fn par_select_owned_indices<Idx>(&'a self, indices: Vec<Idx>) -> ParSelectIndicesIter<'a, Self, IntoIter<Idx>, Unindexed>
+        where
+            Self: Index<Idx>,
+            Idx: Copy + Sync + Send,
+        {
+            self.par_select_with_iter(indices)
+        }
+
+        /// Collect references to the elements at a slice of indices, in the same order
+        /// as the indices, while visiting the collection in ascending index order.
+        /// 
+        /// Parallel form of [`select_indices_localized`](crate::SelectIndices::select_indices_localized).
+        /// Each thread fetches a contiguous run of the sorted indices.
+        fn par_select_indices_localized<Idx>(&'a self, indices: &[Idx]) -> IntoIter<&'a <Self as Index<Idx>>::Output>
+        where
+            Self: Index<Idx> + Sync,
+            <Self as Index<Idx>>::Output: Sync,
+            Idx: Copy + Ord + Sync + Send,
+        {
+            let mut order: Vec<(Idx, usize)> = indices.par_iter().copied().zip(0..indices.len()).collect();
+            order.par_sort_unstable();
+
+            let mut refs: Vec<&'a <Self as Index<Idx>>::Output> = Vec::with_capacity(indices.len());
+            let slots: ForceSync<*mut MaybeUninit<&'a <Self as Index<Idx>>::Output>> = unsafe { ForceSync::new(refs.spare_capacity_mut().as_mut_ptr()) };
+            order.into_par_iter().for_each(|(index, position)| {
+                // Safety: Every position appears exactly once in the sorted order,
+                // so no two threads write to the same slot.
+                unsafe { (*(*slots).add(position)).write(self.index(index)); }
+            });
+            // Safety: Every position from 0 to indices.len() was written.
+            unsafe { refs.set_len(indices.len()) };
+            refs.into_par_iter()
+        }
     }
 
     impl<D> ParSelectIndices<'_> for D
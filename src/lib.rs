@@ -35,6 +35,12 @@ mod immutable;
 
 mod mutable;
 
+mod gather;
+
+mod pattern;
+
+mod prefetch;
+
 pub(crate) mod indexed_type {
     pub struct Unindexed;
     pub struct Indexed;
@@ -48,16 +54,35 @@ pub(crate) mod iter_type {
 
 pub use crate::{
     immutable::traits::SelectIndices,
-    mutable::traits::{
-        OneToOne,
-        SelectIndicesMut,
+    mutable::{
+        traits::{
+            OneToOne,
+            SelectIndicesMut,
+        },
+        vec::SelectIndicesVec,
+        selection::SelectionMut,
+        zip::{ ZipSource, select_zip_mut },
+        neighbors::Neighbors,
+        csr::CsrNeighbors,
+        family::Children,
+        disjoint::{ DisjointMut, AlreadyBorrowed },
+        groups::Group,
+        unique::{ UniqueIndexIter, SortedDedup, IndexSet },
     },
+    pattern::IndexPattern,
+    prefetch::PrefetchIndex,
+    gather::{ GatherSimd, GatherPath },
 };
 
 #[cfg(feature = "rayon")]
 pub use crate::{
     immutable::traits::ParSelectIndices,
-    mutable::traits::ParSelectIndicesMut,
+    mutable::{
+        traits::ParSelectIndicesMut,
+        zip::par_select_zip_mut,
+        labels::{ LabelGroup, ElementCount },
+        unique::ParIndexSet,
+    },
 };
 
 pub mod prelude {
@@ -1,5 +1,8 @@
 use std::ops::IndexMut;
-use super::iter::SeqSelectIndicesMutIter;
+use super::{
+    iter::SeqSelectIndicesMutIter,
+    validate::element_address,
+};
 use crate::indexed_type::{ Unindexed, Indexed };
 
 mod unindexed {
@@ -19,7 +22,7 @@ mod unindexed {
                 let ptr: *mut _ = self.data;
 
                 let val_ref = unsafe { ptr.as_mut().unwrap().index_mut(index) };
-                let val_ptr = (val_ref as *mut Data::Output).cast::<*mut ()>() as usize;
+                let val_ptr = element_address(val_ref);
 
                 assert!(self.visited_refs.insert(val_ptr), "select_indices_mut was passed duplicate indices!");
 
@@ -53,7 +56,7 @@ mod unindexed {
                 let ptr: *mut _ = self.data;
 
                 let val_ref = unsafe { ptr.as_mut().unwrap().index_mut(index) };
-                let val_ptr = (val_ref as *mut Data::Output).cast::<*mut ()>() as usize;
+                let val_ptr = element_address(val_ref);
 
                 assert!(self.visited_refs.insert(val_ptr), "select_indices_mut was passed duplicate indices!");
 
@@ -80,7 +83,7 @@ mod indexed {
                 let ptr: *mut _ = self.data;
 
                 let val_ref = unsafe { ptr.as_mut().unwrap().index_mut(index) };
-                let val_ptr = (val_ref as *mut Data::Output).cast::<*mut ()>() as usize;
+                let val_ptr = element_address(val_ref);
 
                 assert!(self.visited_refs.insert(val_ptr), "select_indices_mut was passed duplicate indices!");
 
@@ -109,7 +112,7 @@ mod indexed {
                 let ptr: *mut _ = self.data;
 
                 let val_ref = unsafe { ptr.as_mut().unwrap().index_mut(index) };
-                let val_ptr = (val_ref as *mut Data::Output).cast::<*mut ()>() as usize;
+                let val_ptr = element_address(val_ref);
 
                 assert!(self.visited_refs.insert(val_ptr), "select_indices_mut was passed duplicate indices!");
 
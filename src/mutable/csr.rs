@@ -0,0 +1,121 @@
+use std::{
+    marker::PhantomData,
+    slice::Iter,
+    iter::Copied,
+};
+use crate::{
+    prelude::*,
+    indexed_type::Unindexed,
+    iter_type::Sequential,
+    immutable::iter::{ SelectIndicesIter, SeqSelectIndicesIter },
+    mutable::validate::assert_in_bounds,
+};
+#[cfg(feature = "rayon")]
+use crate::iter_type::Parallel;
+
+/// Immutably iterates through the neighbours of one frontier node selected by
+/// [`select_csr_mut`](crate::SelectIndicesMut::select_csr_mut).
+pub type CsrNeighbors<'a, Data> = SeqSelectIndicesIter<'a, Data, Copied<Iter<'a, usize>>, Unindexed>;
+
+/// Mutably iterates, with a frontier of node indices, through a [`OneToOne`] collection
+/// of node data, while immutably reading the neighbours of each node from a graph
+/// in compressed sparse row form.
+pub struct SelectCsrMutIter<'a, Data, Indices, IterType>
+where
+    Data: ?Sized,
+{
+    pub(crate) data: &'a mut Data,
+    pub(crate) offsets: &'a [usize],
+    pub(crate) targets: &'a [usize],
+    pub(crate) frontier: Indices,
+    pub(crate) _phantom: PhantomData<IterType>,
+}
+
+/// Return type for [`select_csr_mut`](crate::SelectIndicesMut::select_csr_mut).
+pub type SeqSelectCsrMutIter<'a, Data, Indices> = SelectCsrMutIter<'a, Data, Indices, Sequential>;
+#[cfg(feature = "rayon")]
+/// Return type for [`par_select_csr_mut`](crate::ParSelectIndicesMut::par_select_csr_mut).
+pub type ParSelectCsrMutIter<'a, Data, Indices> = SelectCsrMutIter<'a, Data, Indices, Parallel>;
+
+/// Panics if the frontier contains a duplicate, a node outside of the graph, or a
+/// node that is a neighbour of any frontier node.
+pub(crate) fn assert_csr_frontier(offsets: &[usize], targets: &[usize], frontier: &[usize], caller: &str)
+{
+    let node_count = offsets.len().saturating_sub(1);
+    assert_in_bounds(frontier, node_count, caller);
+
+    let mut in_frontier = vec![false; node_count];
+    for &node in frontier {
+        assert!(!in_frontier[node], "{} was passed duplicate indices!", caller);
+        in_frontier[node] = true;
+    }
+
+    for &node in frontier {
+        assert!(
+            !csr_neighbors(offsets, targets, node).iter().any(|&neighbor| in_frontier.get(neighbor) == Some(&true)),
+            "{} was passed a frontier node that is also a neighbour!", caller,
+        );
+    }
+}
+
+/// Returns the targets of the edges leaving `node`.
+pub(crate) fn csr_neighbors<'a>(offsets: &[usize], targets: &'a [usize], node: usize) -> &'a [usize]
+{
+    &targets[offsets[node]..offsets[node + 1]]
+}
+
+impl<'a, Data, Indices> Iterator for SeqSelectCsrMutIter<'a, Data, Indices>
+where
+    Data: ?Sized + OneToOne<usize>,
+    Data::Output: 'a,
+    Indices: Iterator<Item = usize>,
+{
+    type Item = (&'a mut Data::Output, CsrNeighbors<'a, Data>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.frontier.next().map(|node| {
+            let ptr: *mut Data = self.data;
+
+            // Safety: The frontier is unique, and no frontier node is a
+            // neighbour, so the mutable node is never aliased by another reference.
+            let node_data = unsafe { ptr.as_mut().unwrap().index_mut(node) };
+            let neighbors = SelectIndicesIter {
+                data: unsafe { ptr.as_ref().unwrap() },
+                indices: csr_neighbors(self.offsets, self.targets, node).iter().copied(),
+                _phantom: Default::default(),
+            };
+            (node_data, neighbors)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.frontier.size_hint()
+    }
+}
+
+impl<'a, Data, Indices> DoubleEndedIterator for SeqSelectCsrMutIter<'a, Data, Indices>
+where
+    Data: ?Sized + OneToOne<usize>,
+    Data::Output: 'a,
+    Indices: DoubleEndedIterator<Item = usize>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.frontier.next_back().map(|node| {
+            let ptr: *mut Data = self.data;
+            let node_data = unsafe { ptr.as_mut().unwrap().index_mut(node) };
+            let neighbors = SelectIndicesIter {
+                data: unsafe { ptr.as_ref().unwrap() },
+                indices: csr_neighbors(self.offsets, self.targets, node).iter().copied(),
+                _phantom: Default::default(),
+            };
+            (node_data, neighbors)
+        })
+    }
+}
+
+impl<'a, Data, Indices> ExactSizeIterator for SeqSelectCsrMutIter<'a, Data, Indices>
+where
+    Data: ?Sized + OneToOne<usize>,
+    Data::Output: 'a,
+    Indices: ExactSizeIterator<Item = usize>,
+{}
\ No newline at end of file
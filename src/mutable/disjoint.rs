@@ -0,0 +1,79 @@
+use std::{
+    collections::HashSet,
+    hash::Hash,
+    error::Error,
+    fmt,
+};
+use crate::prelude::*;
+
+/// A handle to a [`OneToOne`] collection that hands out mutable references to
+/// one element at a time, and refuses to hand out the same element twice.
+///
+/// Every reference lives as long as the handle's borrow of the collection, so
+/// earlier references can still be used after later calls to
+/// [`get_mut`](DisjointMut::get_mut). Elements are tracked by index, and
+/// [`OneToOne`] guarantees that different indices are different elements.
+///
+/// Return type for [`disjoint_mut`](crate::SelectIndicesMut::disjoint_mut).
+pub struct DisjointMut<'a, Data, Idx>
+where
+    Data: ?Sized,
+{
+    pub(crate) data: &'a mut Data,
+    pub(crate) borrowed: HashSet<Idx>,
+}
+
+/// The error returned by [`DisjointMut::get_mut`] when the element at an index
+/// has already been handed out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlreadyBorrowed;
+
+impl fmt::Display for AlreadyBorrowed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the element at this index is already mutably borrowed")
+    }
+}
+
+impl Error for AlreadyBorrowed {}
+
+impl<'a, Data, Idx> DisjointMut<'a, Data, Idx>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Idx: Eq + Hash + Copy,
+{
+    /// Returns a mutable reference to the element at `index`, or [`AlreadyBorrowed`]
+    /// if that element was already returned by this handle.
+    ///
+    /// ```rust
+    /// use select_indices::prelude::*;
+    ///
+    /// let mut data = [1, 2, 3, 4];
+    /// let mut handle = data.disjoint_mut();
+    ///
+    /// let a = handle.get_mut(3).unwrap();
+    /// let b = handle.get_mut(1).unwrap();
+    /// assert_eq!(handle.get_mut(3), Err(AlreadyBorrowed));
+    ///
+    /// std::mem::swap(a, b);
+    /// assert_eq!(data, [1, 4, 3, 2]);
+    /// ```
+    pub fn get_mut(&mut self, index: Idx) -> Result<&'a mut Data::Output, AlreadyBorrowed>
+    {
+        if self.borrowed.contains(&index) {
+            return Err(AlreadyBorrowed);
+        }
+
+        let ptr: *mut Data = self.data;
+
+        // Safety: The index has never been handed out before, and `OneToOne`
+        // guarantees that a different index means a different element.
+        let val_ref = unsafe { ptr.as_mut().unwrap().index_mut(index) };
+        self.borrowed.insert(index);
+        Ok(val_ref)
+    }
+
+    /// Returns the number of elements this handle has handed out.
+    pub fn borrowed_len(&self) -> usize {
+        self.borrowed.len()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+use std::{
+    hash::Hash,
+    slice::Iter,
+    iter::Copied,
+};
+use crate::{
+    indexed_type::Unindexed,
+    mutable::{
+        iter::SeqSelectIndicesUncheckedMutIter,
+        validate::assert_unique,
+    },
+};
+
+/// Mutably iterates through the children of one parent selected by
+/// [`select_one_and_many_mut`](crate::SelectIndicesMut::select_one_and_many_mut).
+pub type Children<'a, Data, Idx> = SeqSelectIndicesUncheckedMutIter<'a, Data, Copied<Iter<'a, Idx>>, Unindexed>;
+
+/// Mutably iterates, with a list of parents and their children, through a
+/// [`OneToOne`](crate::OneToOne) collection, producing each parent mutably
+/// alongside a mutable iterator over its children.
+///
+/// Return type for [`par_select_one_and_many_mut`](crate::ParSelectIndicesMut::par_select_one_and_many_mut).
+#[cfg(feature = "rayon")]
+pub struct ParSelectFamiliesMutIter<'a, Data, Idx>
+where
+    Data: ?Sized,
+{
+    pub(crate) data: &'a mut Data,
+    pub(crate) families: &'a [(Idx, &'a [Idx])],
+}
+
+/// Panics if a parent is one of its own children, or if any index appears
+/// in more than one place across all families.
+pub(crate) fn assert_disjoint_families<Idx>(families: &[(Idx, &[Idx])], caller: &str)
+where
+    Idx: Eq + Hash + Copy,
+{
+    assert!(
+        families.iter().all(|(parent, children)| !children.contains(parent)),
+        "{} was passed a parent that is also a child!", caller,
+    );
+    let indices: Vec<Idx> = families.iter()
+        .flat_map(|&(parent, children)| std::iter::once(parent).chain(children.iter().copied()))
+        .collect();
+    assert_unique(&indices, caller);
+}
\ No newline at end of file
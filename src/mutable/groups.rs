@@ -0,0 +1,94 @@
+use std::{
+    marker::PhantomData,
+    slice::Iter,
+    iter::Copied,
+};
+use crate::{
+    prelude::*,
+    indexed_type::Unindexed,
+    iter_type::Sequential,
+    mutable::iter::{ SelectIndicesMutIter, SeqSelectIndicesUncheckedMutIter },
+};
+#[cfg(feature = "rayon")]
+use crate::iter_type::Parallel;
+
+/// Mutably iterates through the elements of one group selected by
+/// [`select_groups_mut`](crate::SelectIndicesMut::select_groups_mut).
+pub type Group<'a, Data, Idx> = SeqSelectIndicesUncheckedMutIter<'a, Data, Copied<Iter<'a, Idx>>, Unindexed>;
+
+/// Iterates, with a list of disjoint groups of indices, through a [`OneToOne`]
+/// collection, producing a mutable iterator over each group.
+pub struct SelectGroupsMutIter<'a, Data, Groups, Idx, IterType>
+where
+    Data: ?Sized,
+{
+    pub(crate) data: &'a mut Data,
+    pub(crate) groups: Groups,
+    pub(crate) _phantom: PhantomData<(Idx, IterType)>,
+}
+
+/// Return type for [`select_groups_mut`](crate::SelectIndicesMut::select_groups_mut).
+pub type SeqSelectGroupsMutIter<'a, Data, Groups, Idx> = SelectGroupsMutIter<'a, Data, Groups, Idx, Sequential>;
+#[cfg(feature = "rayon")]
+/// Return type for [`par_select_groups_mut`](crate::ParSelectIndicesMut::par_select_groups_mut).
+pub type ParSelectGroupsMutIter<'a, Data, Groups, Idx> = SelectGroupsMutIter<'a, Data, Groups, Idx, Parallel>;
+
+impl<'a, Data, Groups, G, Idx> Iterator for SeqSelectGroupsMutIter<'a, Data, Groups, Idx>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Data::Output: 'a,
+    Groups: Iterator<Item = &'a G>,
+    G: 'a + AsRef<[Idx]> + ?Sized,
+    Idx: 'a + Copy,
+{
+    type Item = Group<'a, Data, Idx>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.groups.next().map(|group| {
+            let ptr: *mut Data = self.data;
+
+            // Safety: No index appears in more than one group, so no two
+            // groups can produce the same element.
+            SelectIndicesMutIter {
+                data: unsafe { ptr.as_mut().unwrap() },
+                indices: group.as_ref().iter().copied(),
+                visited_refs: (),
+                _phantom: Default::default(),
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.groups.size_hint()
+    }
+}
+
+impl<'a, Data, Groups, G, Idx> DoubleEndedIterator for SeqSelectGroupsMutIter<'a, Data, Groups, Idx>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Data::Output: 'a,
+    Groups: DoubleEndedIterator<Item = &'a G>,
+    G: 'a + AsRef<[Idx]> + ?Sized,
+    Idx: 'a + Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.groups.next_back().map(|group| {
+            let ptr: *mut Data = self.data;
+            SelectIndicesMutIter {
+                data: unsafe { ptr.as_mut().unwrap() },
+                indices: group.as_ref().iter().copied(),
+                visited_refs: (),
+                _phantom: Default::default(),
+            }
+        })
+    }
+}
+
+impl<'a, Data, Groups, G, Idx> ExactSizeIterator for SeqSelectGroupsMutIter<'a, Data, Groups, Idx>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Data::Output: 'a,
+    Groups: ExactSizeIterator<Item = &'a G>,
+    G: 'a + AsRef<[Idx]> + ?Sized,
+    Idx: 'a + Copy,
+{}
\ No newline at end of file
@@ -1,10 +1,15 @@
 use std::{
     marker::PhantomData,
     collections::HashSet,
+    vec::IntoIter,
 };
 #[cfg(feature = "rayon")]
 use std::sync::Mutex;
-use crate::indexed_type::{ Unindexed, Indexed };
+use crate::{
+    indexed_type::{ Unindexed, Indexed },
+    mutable::validate::assert_unique,
+    prefetch::{ PrefetchIndex, PrefetchIndices },
+};
 
 /// Mutably iterates, with a set of indices, through a [`OneToOne`](crate::OneToOne) collection
 pub struct SelectIndicesMutIter<'a, Data, Indices, VisitedSet, IndexedType>
@@ -32,6 +37,52 @@ impl<'a, Data, Indices, VisitedSet> SelectIndicesMutIter<'a, Data, Indices, Visi
     }
 }
 
+impl<'a, Data, Indices, VisitedSet, IndexedType> SelectIndicesMutIter<'a, Data, Indices, VisitedSet, IndexedType>
+where
+    Data: ?Sized,
+    Indices: Iterator,
+    Indices::Item: Copy,
+{
+    /// Narrows the iterator down to the given positions of its remaining indices,
+    /// so that the `k`-th item is the item at index `remaining[sub_indices[k]]`.
+    /// 
+    /// Only `sub_indices` is checked for uniqueness. If the remaining indices were
+    /// already unique, the narrowed indices are too, so an iterator that was
+    /// checked up front stays unchecked, and an iterator that checks every index
+    /// keeps checking.
+    pub fn select(self, sub_indices: &[usize]) -> SelectIndicesMutIter<'a, Data, IntoIter<Indices::Item>, VisitedSet, IndexedType>
+    {
+        assert_unique(sub_indices, "select");
+
+        let outer: Vec<Indices::Item> = self.indices.collect();
+        let indices: Vec<Indices::Item> = sub_indices.iter().map(|&k| outer[k]).collect();
+        SelectIndicesMutIter {
+            data: self.data,
+            indices: indices.into_iter(),
+            visited_refs: self.visited_refs,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Prefetches the element `distance` indices ahead of the one being produced,
+    /// so that it is more likely to be in the cache by the time it is reached.
+    /// 
+    /// This is the mutable form of the `prefetch` method of the iterator returned by
+    /// [`select_indices`](crate::SelectIndices::select_indices).
+    pub fn prefetch(self, distance: usize) -> SelectIndicesMutIter<'a, Data, PrefetchIndices<Data, Indices>, VisitedSet, IndexedType>
+    where
+        Data: PrefetchIndex<Indices::Item>,
+    {
+        let data: *const Data = self.data;
+        SelectIndicesMutIter {
+            data: self.data,
+            indices: PrefetchIndices::new(data, self.indices, distance),
+            visited_refs: self.visited_refs,
+            _phantom: Default::default(),
+        }
+    }
+}
+
 /// Return type for [`select_indices_mut_unchecked`](crate::SelectIndicesMut::select_indices_mut_unchecked).
 pub type SelectIndicesUncheckedMutIter<'a, Data, Indices, IndexedType> = SelectIndicesMutIter<'a, Data, Indices, (), IndexedType>;
 
@@ -0,0 +1,80 @@
+use std::vec::IntoIter;
+use crate::{
+    prelude::*,
+    indexed_type::Unindexed,
+    mutable::iter::SeqSelectIndicesUncheckedMutIter,
+};
+
+/// Mutably iterates through the elements of one class selected by
+/// [`par_select_by_labels_mut`](crate::ParSelectIndicesMut::par_select_by_labels_mut).
+pub type LabelGroup<'a, Data> = SeqSelectIndicesUncheckedMutIter<'a, Data, IntoIter<usize>, Unindexed>;
+
+/// Iterates in parallel, with a label for every element, through a [`OneToOne`](crate::OneToOne)
+/// collection, producing each label alongside a mutable iterator over its elements.
+///
+/// Labels are produced in ascending order, and the elements of each label in
+/// ascending index order. Labels with no elements are skipped.
+///
+/// Return type for [`par_select_by_labels_mut`](crate::ParSelectIndicesMut::par_select_by_labels_mut).
+pub struct ParSelectByLabelsMut<'a, Data>
+where
+    Data: ?Sized,
+{
+    pub(crate) data: &'a mut Data,
+    pub(crate) buckets: Vec<(u32, Vec<usize>)>,
+}
+
+/// A collection that knows how many elements it holds, so that
+/// [`par_select_by_labels_mut`](crate::ParSelectIndicesMut::par_select_by_labels_mut)
+/// can check that every element has a label.
+///
+/// This is implemented for slices, arrays, [`Vec`]s and [`SelectionMut`]s.
+/// Implement it for your own collection to use
+/// [`par_select_by_labels_mut`](crate::ParSelectIndicesMut::par_select_by_labels_mut) with it.
+pub trait ElementCount
+{
+    /// Returns the number of elements in the collection.
+    fn element_count(&self) -> usize;
+}
+
+impl<T> ElementCount for [T]
+{
+    fn element_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, const N: usize> ElementCount for [T; N]
+{
+    fn element_count(&self) -> usize {
+        N
+    }
+}
+
+impl<T> ElementCount for Vec<T>
+{
+    fn element_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<'a, Data, Idx> ElementCount for SelectionMut<'a, Data, Idx>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Idx: Copy,
+{
+    fn element_count(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<S, D> ElementCount for ::ndarray::ArrayBase<S, D>
+where
+    S: ::ndarray::RawData,
+    D: ::ndarray::Dimension,
+{
+    fn element_count(&self) -> usize {
+        self.len()
+    }
+}
\ No newline at end of file
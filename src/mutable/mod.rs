@@ -1,7 +1,21 @@
 pub mod traits;
 pub mod iter;
+pub mod vec;
+pub mod selection;
+pub mod zip;
+pub mod neighbors;
+pub mod pairs;
+pub mod csr;
+pub mod family;
+pub mod disjoint;
+pub mod read_write;
+pub mod groups;
+pub mod unique;
+#[cfg(feature = "rayon")]
+pub mod labels;
 
 mod unchecked;
 mod checked;
+mod validate;
 #[cfg(feature = "rayon")]
-mod rayon;
\ No newline at end of file
+pub(crate) mod rayon;
\ No newline at end of file
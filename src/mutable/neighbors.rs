@@ -0,0 +1,94 @@
+use std::{
+    marker::PhantomData,
+    collections::HashSet,
+    hash::Hash,
+    vec::IntoIter,
+};
+use crate::{
+    prelude::*,
+    indexed_type::Unindexed,
+    iter_type::Sequential,
+    immutable::iter::{ SelectIndicesIter, SeqSelectIndicesIter },
+};
+#[cfg(feature = "rayon")]
+use crate::iter_type::Parallel;
+
+/// Immutably iterates through the neighbours of one element selected by
+/// [`select_with_neighbors_mut`](crate::SelectIndicesMut::select_with_neighbors_mut).
+pub type Neighbors<'a, Data, Idx> = SeqSelectIndicesIter<'a, Data, IntoIter<Idx>, Unindexed>;
+
+/// Mutably iterates, with a set of centre indices, through a [`OneToOne`] collection,
+/// while immutably reading the neighbours of each centre.
+pub struct SelectNeighborsMutIter<'a, Data, Indices, Idx, NeighborFn, IterType>
+where
+    Data: ?Sized,
+{
+    pub(crate) data: &'a mut Data,
+    pub(crate) centers: Indices,
+    pub(crate) center_set: HashSet<Idx>,
+    pub(crate) neighbor_fn: NeighborFn,
+    pub(crate) _phantom: PhantomData<IterType>,
+}
+
+/// Return type for [`select_with_neighbors_mut`](crate::SelectIndicesMut::select_with_neighbors_mut).
+pub type SeqSelectNeighborsMutIter<'a, Data, Indices, Idx, NeighborFn> = SelectNeighborsMutIter<'a, Data, Indices, Idx, NeighborFn, Sequential>;
+#[cfg(feature = "rayon")]
+/// Return type for [`par_select_with_neighbors_mut`](crate::ParSelectIndicesMut::par_select_with_neighbors_mut).
+pub type ParSelectNeighborsMutIter<'a, Data, Indices, Idx, NeighborFn> = SelectNeighborsMutIter<'a, Data, Indices, Idx, NeighborFn, Parallel>;
+
+/// Collects the neighbours of a centre, and panics if any of them is a centre.
+pub(crate) fn collect_neighbors<Idx, N>(neighbors: N, center_set: &HashSet<Idx>, caller: &str) -> Vec<Idx>
+where
+    Idx: Eq + Hash,
+    N: IntoIterator<Item = Idx>,
+{
+    let neighbors: Vec<Idx> = neighbors.into_iter().collect();
+    assert!(
+        !neighbors.iter().any(|neighbor| center_set.contains(neighbor)),
+        "{} was passed a centre that is also a neighbour!", caller,
+    );
+    neighbors
+}
+
+impl<'a, Data, Indices, Idx, NeighborFn, N> Iterator for SeqSelectNeighborsMutIter<'a, Data, Indices, Idx, NeighborFn>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Data::Output: 'a,
+    Indices: Iterator<Item = Idx>,
+    Idx: Copy + Eq + Hash,
+    NeighborFn: FnMut(Idx) -> N,
+    N: IntoIterator<Item = Idx>,
+{
+    type Item = (&'a mut Data::Output, Neighbors<'a, Data, Idx>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.centers.next().map(|index| {
+            let neighbors = collect_neighbors((self.neighbor_fn)(index), &self.center_set, "select_with_neighbors_mut");
+            let ptr: *mut Data = self.data;
+
+            // Safety: The centres are unique, and none of them are neighbours,
+            // so the mutable centre is never aliased by another reference.
+            let center = unsafe { ptr.as_mut().unwrap().index_mut(index) };
+            let neighbors = SelectIndicesIter {
+                data: unsafe { ptr.as_ref().unwrap() },
+                indices: neighbors.into_iter(),
+                _phantom: Default::default(),
+            };
+            (center, neighbors)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.centers.size_hint()
+    }
+}
+
+impl<'a, Data, Indices, Idx, NeighborFn, N> ExactSizeIterator for SeqSelectNeighborsMutIter<'a, Data, Indices, Idx, NeighborFn>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Data::Output: 'a,
+    Indices: ExactSizeIterator<Item = Idx>,
+    Idx: Copy + Eq + Hash,
+    NeighborFn: FnMut(Idx) -> N,
+    N: IntoIterator<Item = Idx>,
+{}
\ No newline at end of file
@@ -0,0 +1,98 @@
+use std::hash::Hash;
+use crate::{
+    prelude::*,
+    mutable::validate::assert_unique,
+};
+
+/// Mutably iterates, with a list of index pairs, through a [`OneToOne`] collection,
+/// producing both elements of each pair at once.
+///
+/// Return type for [`select_pairs_mut`](crate::SelectIndicesMut::select_pairs_mut).
+pub struct SelectPairsMutIter<'a, Data, Pairs>
+where
+    Data: ?Sized,
+{
+    pub(crate) data: &'a mut Data,
+    pub(crate) pairs: Pairs,
+}
+
+/// Panics if any pair connects an index to itself, or if any index appears
+/// in more than one place across all pairs.
+pub(crate) fn assert_disjoint_pairs<Idx>(pairs: &[(Idx, Idx)], caller: &str)
+where
+    Idx: Eq + Hash + Copy,
+{
+    assert!(
+        pairs.iter().all(|(a, b)| a != b),
+        "{} was passed a pair from an index to itself!", caller,
+    );
+    let indices: Vec<Idx> = pairs.iter().flat_map(|&(a, b)| [a, b]).collect();
+    assert_unique(&indices, caller);
+}
+
+impl<'a, Data, Pairs, Idx> Iterator for SelectPairsMutIter<'a, Data, Pairs>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Data::Output: 'a,
+    Pairs: Iterator<Item = (Idx, Idx)>,
+    Idx: Copy,
+{
+    type Item = (&'a mut Data::Output, &'a mut Data::Output);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pairs.next().map(|(a, b)| {
+            let ptr: *mut Data = self.data;
+            unsafe { (ptr.as_mut().unwrap().index_mut(a), ptr.as_mut().unwrap().index_mut(b)) }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.pairs.size_hint()
+    }
+}
+
+impl<'a, Data, Pairs, Idx> DoubleEndedIterator for SelectPairsMutIter<'a, Data, Pairs>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Data::Output: 'a,
+    Pairs: DoubleEndedIterator<Item = (Idx, Idx)>,
+    Idx: Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.pairs.next_back().map(|(a, b)| {
+            let ptr: *mut Data = self.data;
+            unsafe { (ptr.as_mut().unwrap().index_mut(a), ptr.as_mut().unwrap().index_mut(b)) }
+        })
+    }
+}
+
+impl<'a, Data, Pairs, Idx> ExactSizeIterator for SelectPairsMutIter<'a, Data, Pairs>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Data::Output: 'a,
+    Pairs: ExactSizeIterator<Item = (Idx, Idx)>,
+    Idx: Copy,
+{}
+
+/// Mutably visits, with a list of index pairs that may share indices, both
+/// elements of each pair in parallel.
+///
+/// The pairs are split into rounds in which no index appears twice. Each round
+/// runs in parallel, and the rounds run one after another. Pairs that share an
+/// index are always visited in the order they were given.
+///
+/// This is not a [`ParallelIterator`](rayon::iter::ParallelIterator). Pairs from
+/// different rounds can share an element, so their references must never be
+/// alive at the same time, as they could be with adapters such as `collect`.
+/// The pairs can only be visited with [`for_each`](ParPairRoundsMut::for_each),
+/// which finishes each round before the next one starts.
+///
+/// Return type for [`par_select_pairs_mut`](crate::ParSelectIndicesMut::par_select_pairs_mut).
+#[cfg(feature = "rayon")]
+pub struct ParPairRoundsMut<'a, Data, Idx>
+where
+    Data: ?Sized,
+{
+    pub(crate) data: &'a mut Data,
+    pub(crate) rounds: Vec<(Vec<Idx>, Vec<Idx>)>,
+}
\ No newline at end of file
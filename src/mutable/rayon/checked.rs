@@ -1,6 +1,9 @@
 use crate::{
     prelude::*,
-    mutable::iter::ParSelectIndicesMutIter,
+    mutable::{
+        iter::ParSelectIndicesMutIter,
+        validate::element_address,
+    },
     indexed_type::{ Unindexed, Indexed },
 };
 use rayon::{
@@ -32,7 +35,7 @@ mod unindexed {
                 
                 let val_ref = data.index_mut(index);
 
-                let val_ptr_num = (val_ref as *mut Data::Output).cast::<*mut ()>() as usize;
+                let val_ptr_num = element_address(val_ref);
 
                 assert!(visited_refs.lock().unwrap().insert(val_ptr_num), "par_select_indices_mut was passed duplicate indices!");
 
@@ -60,7 +63,7 @@ mod unindexed {
                 
                 let val_ref = data.index_mut(index);
 
-                let val_ptr_num = (val_ref as *mut Data::Output).cast::<*mut ()>() as usize;
+                let val_ptr_num = element_address(val_ref);
 
                 assert!(visited_refs.lock().unwrap().insert(val_ptr_num), "par_select_indices_mut was passed duplicate indices!");
 
@@ -76,7 +79,7 @@ mod unindexed {
                 
                 let val_ref = data.index_mut(index);
 
-                let val_ptr_num = (val_ref as *mut Data::Output).cast::<*mut ()>() as usize;
+                let val_ptr_num = element_address(val_ref);
 
                 assert!(visited_refs.lock().unwrap().insert(val_ptr_num), "par_select_indices_mut was passed duplicate indices!");
 
@@ -108,7 +111,7 @@ mod indexed {
                 
                 let val_ref = data.index_mut(index);
 
-                let val_ptr_num = (val_ref as *mut Data::Output).cast::<*mut ()>() as usize;
+                let val_ptr_num = element_address(val_ref);
 
                 assert!(visited_refs.lock().unwrap().insert(val_ptr_num), "par_select_indices_mut was passed duplicate indices!");
 
@@ -136,7 +139,7 @@ mod indexed {
                 
                 let val_ref = data.index_mut(index);
 
-                let val_ptr_num = (val_ref as *mut Data::Output).cast::<*mut ()>() as usize;
+                let val_ptr_num = element_address(val_ref);
 
                 assert!(visited_refs.lock().unwrap().insert(val_ptr_num), "par_select_indices_mut was passed duplicate indices!");
 
@@ -152,7 +155,7 @@ mod indexed {
                 
                 let val_ref = data.index_mut(index);
 
-                let val_ptr_num = (val_ref as *mut Data::Output).cast::<*mut ()>() as usize;
+                let val_ptr_num = element_address(val_ref);
 
                 assert!(visited_refs.lock().unwrap().insert(val_ptr_num), "par_select_indices_mut was passed duplicate indices!");
 
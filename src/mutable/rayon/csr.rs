@@ -0,0 +1,75 @@
+use crate::{
+    prelude::*,
+    immutable::iter::SelectIndicesIter,
+    mutable::csr::{ ParSelectCsrMutIter, CsrNeighbors, csr_neighbors },
+};
+use rayon::{
+    prelude::*,
+    iter::plumbing::{ Consumer, UnindexedConsumer, ProducerCallback },
+};
+use force_send_sync::Sync as ForceSync;
+
+impl<'a, Data, Indices> ParallelIterator for ParSelectCsrMutIter<'a, Data, Indices>
+where
+    Data: Send + Sync + OneToOne<usize>,
+    Data::Output: 'a + Send,
+    Indices: ParallelIterator<Item = usize>,
+{
+    type Item = (&'a mut Data::Output, CsrNeighbors<'a, Data>);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>
+    {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        let (offsets, targets) = (self.offsets, self.targets);
+        self.frontier.map(|node| {
+            let node_data = unsafe { (*ptr).as_mut().unwrap().index_mut(node) };
+            let neighbors = SelectIndicesIter {
+                data: unsafe { (*ptr).as_ref().unwrap() },
+                indices: csr_neighbors(offsets, targets, node).iter().copied(),
+                _phantom: Default::default(),
+            };
+            (node_data, neighbors)
+        }).drive_unindexed(consumer)
+    }
+}
+
+impl<'a, Data, Indices> IndexedParallelIterator for ParSelectCsrMutIter<'a, Data, Indices>
+where
+    Data: Send + Sync + OneToOne<usize>,
+    Data::Output: 'a + Send,
+    Indices: IndexedParallelIterator<Item = usize>,
+{
+    fn len(&self) -> usize {
+        self.frontier.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        let (offsets, targets) = (self.offsets, self.targets);
+        self.frontier.map(|node| {
+            let node_data = unsafe { (*ptr).as_mut().unwrap().index_mut(node) };
+            let neighbors = SelectIndicesIter {
+                data: unsafe { (*ptr).as_ref().unwrap() },
+                indices: csr_neighbors(offsets, targets, node).iter().copied(),
+                _phantom: Default::default(),
+            };
+            (node_data, neighbors)
+        }).drive(consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        let (offsets, targets) = (self.offsets, self.targets);
+        self.frontier.map(|node| {
+            let node_data = unsafe { (*ptr).as_mut().unwrap().index_mut(node) };
+            let neighbors = SelectIndicesIter {
+                data: unsafe { (*ptr).as_ref().unwrap() },
+                indices: csr_neighbors(offsets, targets, node).iter().copied(),
+                _phantom: Default::default(),
+            };
+            (node_data, neighbors)
+        }).with_producer(callback)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+use crate::{
+    prelude::*,
+    mutable::{
+        family::{ ParSelectFamiliesMutIter, Children },
+        iter::SelectIndicesMutIter,
+    },
+};
+use rayon::{
+    prelude::*,
+    iter::plumbing::{ Consumer, UnindexedConsumer, ProducerCallback },
+};
+use force_send_sync::Sync as ForceSync;
+
+impl<'a, Data, Idx> ParallelIterator for ParSelectFamiliesMutIter<'a, Data, Idx>
+where
+    Data: Send + Sync + OneToOne<Idx>,
+    Data::Output: 'a + Send,
+    Idx: 'a + Copy + Send + Sync,
+{
+    type Item = (&'a mut Data::Output, Children<'a, Data, Idx>);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>
+    {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        self.families.par_iter().map(|&(parent, children)| {
+            let parent = unsafe { (*ptr).as_mut().unwrap().index_mut(parent) };
+            let children = SelectIndicesMutIter {
+                data: unsafe { (*ptr).as_mut().unwrap() },
+                indices: children.iter().copied(),
+                visited_refs: (),
+                _phantom: Default::default(),
+            };
+            (parent, children)
+        }).drive_unindexed(consumer)
+    }
+}
+
+impl<'a, Data, Idx> IndexedParallelIterator for ParSelectFamiliesMutIter<'a, Data, Idx>
+where
+    Data: Send + Sync + OneToOne<Idx>,
+    Data::Output: 'a + Send,
+    Idx: 'a + Copy + Send + Sync,
+{
+    fn len(&self) -> usize {
+        self.families.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        self.families.par_iter().map(|&(parent, children)| {
+            let parent = unsafe { (*ptr).as_mut().unwrap().index_mut(parent) };
+            let children = SelectIndicesMutIter {
+                data: unsafe { (*ptr).as_mut().unwrap() },
+                indices: children.iter().copied(),
+                visited_refs: (),
+                _phantom: Default::default(),
+            };
+            (parent, children)
+        }).drive(consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        self.families.par_iter().map(|&(parent, children)| {
+            let parent = unsafe { (*ptr).as_mut().unwrap().index_mut(parent) };
+            let children = SelectIndicesMutIter {
+                data: unsafe { (*ptr).as_mut().unwrap() },
+                indices: children.iter().copied(),
+                visited_refs: (),
+                _phantom: Default::default(),
+            };
+            (parent, children)
+        }).with_producer(callback)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,75 @@
+use crate::{
+    prelude::*,
+    mutable::{
+        groups::{ ParSelectGroupsMutIter, Group },
+        iter::SelectIndicesMutIter,
+    },
+};
+use rayon::{
+    prelude::*,
+    iter::plumbing::{ Consumer, UnindexedConsumer, ProducerCallback },
+};
+use force_send_sync::Sync as ForceSync;
+
+impl<'a, Data, Groups, G, Idx> ParallelIterator for ParSelectGroupsMutIter<'a, Data, Groups, Idx>
+where
+    Data: Send + Sync + OneToOne<Idx>,
+    Data::Output: 'a + Send,
+    Groups: ParallelIterator<Item = &'a G>,
+    G: 'a + AsRef<[Idx]> + Sync + ?Sized,
+    Idx: 'a + Copy + Send + Sync,
+{
+    type Item = Group<'a, Data, Idx>;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>
+    {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        self.groups.map(|group| {
+            SelectIndicesMutIter {
+                data: unsafe { (*ptr).as_mut().unwrap() },
+                indices: group.as_ref().iter().copied(),
+                visited_refs: (),
+                _phantom: Default::default(),
+            }
+        }).drive_unindexed(consumer)
+    }
+}
+
+impl<'a, Data, Groups, G, Idx> IndexedParallelIterator for ParSelectGroupsMutIter<'a, Data, Groups, Idx>
+where
+    Data: Send + Sync + OneToOne<Idx>,
+    Data::Output: 'a + Send,
+    Groups: IndexedParallelIterator<Item = &'a G>,
+    G: 'a + AsRef<[Idx]> + Sync + ?Sized,
+    Idx: 'a + Copy + Send + Sync,
+{
+    fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        self.groups.map(|group| {
+            SelectIndicesMutIter {
+                data: unsafe { (*ptr).as_mut().unwrap() },
+                indices: group.as_ref().iter().copied(),
+                visited_refs: (),
+                _phantom: Default::default(),
+            }
+        }).drive(consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        self.groups.map(|group| {
+            SelectIndicesMutIter {
+                data: unsafe { (*ptr).as_mut().unwrap() },
+                indices: group.as_ref().iter().copied(),
+                visited_refs: (),
+                _phantom: Default::default(),
+            }
+        }).with_producer(callback)
+    }
+}
\ No newline at end of file
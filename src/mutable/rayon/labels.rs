@@ -0,0 +1,129 @@
+use crate::{
+    prelude::*,
+    mutable::{
+        labels::{ ParSelectByLabelsMut, LabelGroup, ElementCount },
+        iter::SelectIndicesMutIter,
+    },
+};
+use rayon::{
+    prelude::*,
+    iter::plumbing::{ Consumer, UnindexedConsumer, ProducerCallback },
+};
+use force_send_sync::Sync as ForceSync;
+
+impl<'a, Data> ParSelectByLabelsMut<'a, Data>
+where
+    Data: ?Sized + ElementCount,
+{
+    /// Buckets the element indices by label with a counting sort, so every
+    /// bucket keeps its indices in ascending order. Labels larger than the
+    /// number of elements fall back to a stable sort, to keep the count
+    /// array no larger than the labels.
+    pub(crate) fn new(data: &'a mut Data, labels: &[u32]) -> Self {
+        assert_eq!(
+            labels.len(), data.element_count(),
+            "par_select_by_labels_mut was passed a different number of labels than elements!",
+        );
+
+        let max_label = labels.iter().copied().max().unwrap_or(0) as usize;
+
+        let mut order: Vec<usize> = Vec::with_capacity(labels.len());
+        if max_label <= labels.len() {
+            let mut starts = vec![0; max_label + 2];
+            for &label in labels {
+                starts[label as usize + 1] += 1;
+            }
+            for label in 1..starts.len() {
+                starts[label] += starts[label - 1];
+            }
+            order.resize(labels.len(), 0);
+            for (index, &label) in labels.iter().enumerate() {
+                order[starts[label as usize]] = index;
+                starts[label as usize] += 1;
+            }
+        }
+        else {
+            order.extend(0..labels.len());
+            order.sort_by_key(|&index| labels[index]);
+        }
+
+        // Split the sorted order into runs of equal labels.
+        let mut buckets: Vec<(u32, Vec<usize>)> = Vec::new();
+        for index in order {
+            match buckets.last_mut() {
+                Some((label, bucket)) if *label == labels[index] => bucket.push(index),
+                _ => buckets.push((labels[index], vec![index])),
+            }
+        }
+
+        ParSelectByLabelsMut {
+            data,
+            buckets,
+        }
+    }
+
+    /// Returns the labels that have at least one element, in ascending order.
+    pub fn labels(&self) -> impl Iterator<Item = u32> + '_ {
+        self.buckets.iter().map(|&(label, _)| label)
+    }
+}
+
+impl<'a, Data> ParallelIterator for ParSelectByLabelsMut<'a, Data>
+where
+    Data: Send + Sync + OneToOne<usize>,
+    Data::Output: 'a + Send,
+{
+    type Item = (u32, LabelGroup<'a, Data>);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>
+    {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        self.buckets.into_par_iter().map(|(label, indices)| {
+            let group = SelectIndicesMutIter {
+                data: unsafe { (*ptr).as_mut().unwrap() },
+                indices: indices.into_iter(),
+                visited_refs: (),
+                _phantom: Default::default(),
+            };
+            (label, group)
+        }).drive_unindexed(consumer)
+    }
+}
+
+impl<'a, Data> IndexedParallelIterator for ParSelectByLabelsMut<'a, Data>
+where
+    Data: Send + Sync + OneToOne<usize>,
+    Data::Output: 'a + Send,
+{
+    fn len(&self) -> usize {
+        self.buckets.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        self.buckets.into_par_iter().map(|(label, indices)| {
+            let group = SelectIndicesMutIter {
+                data: unsafe { (*ptr).as_mut().unwrap() },
+                indices: indices.into_iter(),
+                visited_refs: (),
+                _phantom: Default::default(),
+            };
+            (label, group)
+        }).drive(consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        self.buckets.into_par_iter().map(|(label, indices)| {
+            let group = SelectIndicesMutIter {
+                data: unsafe { (*ptr).as_mut().unwrap() },
+                indices: indices.into_iter(),
+                visited_refs: (),
+                _phantom: Default::default(),
+            };
+            (label, group)
+        }).with_producer(callback)
+    }
+}
\ No newline at end of file
@@ -1,2 +1,11 @@
 mod unchecked;
-mod checked;
\ No newline at end of file
+mod checked;
+mod selection;
+mod zip;
+mod neighbors;
+pub(crate) mod pairs;
+mod csr;
+mod family;
+mod read_write;
+mod groups;
+mod labels;
\ No newline at end of file
@@ -0,0 +1,88 @@
+use std::hash::Hash;
+use crate::{
+    prelude::*,
+    immutable::iter::SelectIndicesIter,
+    mutable::neighbors::{ ParSelectNeighborsMutIter, Neighbors, collect_neighbors },
+};
+use rayon::{
+    prelude::*,
+    iter::plumbing::{ Consumer, UnindexedConsumer, ProducerCallback },
+};
+use force_send_sync::Sync as ForceSync;
+
+impl<'a, Data, Indices, Idx, NeighborFn, N> ParallelIterator for ParSelectNeighborsMutIter<'a, Data, Indices, Idx, NeighborFn>
+where
+    Data: Send + Sync + OneToOne<Idx>,
+    Data::Output: 'a + Send,
+    Indices: ParallelIterator<Item = Idx>,
+    Idx: Copy + Eq + Hash + Send + Sync,
+    NeighborFn: Fn(Idx) -> N + Send + Sync,
+    N: IntoIterator<Item = Idx>,
+{
+    type Item = (&'a mut Data::Output, Neighbors<'a, Data, Idx>);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>
+    {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        let center_set = self.center_set;
+        let neighbor_fn = self.neighbor_fn;
+        self.centers.map(|index| {
+            let neighbors = collect_neighbors(neighbor_fn(index), &center_set, "par_select_with_neighbors_mut");
+            let center = unsafe { (*ptr).as_mut().unwrap().index_mut(index) };
+            let neighbors = SelectIndicesIter {
+                data: unsafe { (*ptr).as_ref().unwrap() },
+                indices: neighbors.into_iter(),
+                _phantom: Default::default(),
+            };
+            (center, neighbors)
+        }).drive_unindexed(consumer)
+    }
+}
+
+impl<'a, Data, Indices, Idx, NeighborFn, N> IndexedParallelIterator for ParSelectNeighborsMutIter<'a, Data, Indices, Idx, NeighborFn>
+where
+    Data: Send + Sync + OneToOne<Idx>,
+    Data::Output: 'a + Send,
+    Indices: IndexedParallelIterator<Item = Idx>,
+    Idx: Copy + Eq + Hash + Send + Sync,
+    NeighborFn: Fn(Idx) -> N + Send + Sync,
+    N: IntoIterator<Item = Idx>,
+{
+    fn len(&self) -> usize {
+        self.centers.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        let center_set = self.center_set;
+        let neighbor_fn = self.neighbor_fn;
+        self.centers.map(|index| {
+            let neighbors = collect_neighbors(neighbor_fn(index), &center_set, "par_select_with_neighbors_mut");
+            let center = unsafe { (*ptr).as_mut().unwrap().index_mut(index) };
+            let neighbors = SelectIndicesIter {
+                data: unsafe { (*ptr).as_ref().unwrap() },
+                indices: neighbors.into_iter(),
+                _phantom: Default::default(),
+            };
+            (center, neighbors)
+        }).drive(consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        let center_set = self.center_set;
+        let neighbor_fn = self.neighbor_fn;
+        self.centers.map(|index| {
+            let neighbors = collect_neighbors(neighbor_fn(index), &center_set, "par_select_with_neighbors_mut");
+            let center = unsafe { (*ptr).as_mut().unwrap().index_mut(index) };
+            let neighbors = SelectIndicesIter {
+                data: unsafe { (*ptr).as_ref().unwrap() },
+                indices: neighbors.into_iter(),
+                _phantom: Default::default(),
+            };
+            (center, neighbors)
+        }).with_producer(callback)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,133 @@
+use std::{
+    hash::Hash,
+    collections::HashMap,
+};
+use crate::{
+    prelude::*,
+    mutable::pairs::ParPairRoundsMut,
+};
+use rayon::prelude::*;
+use force_send_sync::Sync as ForceSync;
+
+impl<'a, Data, Idx> ParPairRoundsMut<'a, Data, Idx>
+where
+    Data: Send + OneToOne<Idx>,
+    Data::Output: Send,
+    Idx: Copy + Eq + Hash + Send + Sync,
+{
+    /// Splits the pairs into rounds in which no index appears twice. Each pair
+    /// goes into the round after the last round that used either of its indices.
+    pub(crate) fn new(data: &'a mut Data, pairs: &[(Idx, Idx)]) -> Self {
+        assert!(
+            pairs.iter().all(|(a, b)| a != b),
+            "par_select_pairs_mut was passed a pair from an index to itself!",
+        );
+
+        let mut next_round: HashMap<Idx, usize> = HashMap::new();
+        let mut rounds: Vec<(Vec<Idx>, Vec<Idx>)> = Vec::new();
+        for &(a, b) in pairs {
+            let round = next_round.get(&a).copied().unwrap_or(0)
+                .max(next_round.get(&b).copied().unwrap_or(0));
+            next_round.insert(a, round + 1);
+            next_round.insert(b, round + 1);
+
+            if round == rounds.len() {
+                rounds.push(Default::default());
+            }
+            rounds[round].0.push(a);
+            rounds[round].1.push(b);
+        }
+
+        ParPairRoundsMut {
+            data,
+            rounds,
+        }
+    }
+
+    /// Returns the number of rounds the pairs were split into.
+    pub fn rounds(&self) -> usize {
+        self.rounds.len()
+    }
+
+    /// Calls `op` on both elements of every pair, running the pairs of each
+    /// round in parallel.
+    pub fn for_each<Op>(self, op: Op)
+    where
+        Op: Fn((&mut Data::Output, &mut Data::Output)) + Sync + Send,
+    {
+        let ptr: ForceSync<*mut Data> = unsafe { ForceSync::new(self.data) };
+        for (a_indices, b_indices) in &self.rounds {
+            a_indices.par_iter().zip(b_indices).for_each(|(&a, &b)| {
+                // Safety: No index appears twice within a round, and each round
+                // finishes before the next one starts. Both elements of a pair
+                // come from one reborrow of the collection.
+                unsafe {
+                    let data = (*ptr).as_mut().unwrap();
+                    let a: *mut Data::Output = data.index_mut(a);
+                    let b: *mut Data::Output = data.index_mut(b);
+                    op((&mut *a, &mut *b));
+                }
+            });
+        }
+    }
+}
+
+/// Calls `op` on every unordered pair of elements at `indices`, which must be unique.
+///
+/// The indices are split into blocks. The pairs within each block run first, with
+/// every block in parallel. The pairs between blocks then run in rounds, using a
+/// round-robin tournament so that every pair of blocks meets exactly once and no
+/// block takes part in two tasks of the same round.
+pub(crate) fn par_for_each_pair_mut<Data, Idx, Op>(data: &mut Data, indices: &[Idx], op: Op)
+where
+    Data: Send + OneToOne<Idx>,
+    Data::Output: Send,
+    Idx: Copy + Sync + Send,
+    Op: Fn(&mut Data::Output, &mut Data::Output) + Sync + Send,
+{
+    if indices.is_empty() {
+        return;
+    }
+
+    let block_count = (rayon::current_num_threads() * 2).min(indices.len());
+    let block_len = (indices.len() + block_count - 1) / block_count;
+    let blocks: Vec<&[Idx]> = indices.chunks(block_len).collect();
+
+    let ptr: ForceSync<*mut Data> = unsafe { ForceSync::new(data) };
+    let visit = |a: Idx, b: Idx| {
+        // Safety: a and b are different indices, and no other task is
+        // using the blocks they came from.
+        unsafe { op((*ptr).as_mut().unwrap().index_mut(a), (*ptr).as_mut().unwrap().index_mut(b)) }
+    };
+
+    blocks.par_iter().for_each(|block| {
+        for (i, &a) in block.iter().enumerate() {
+            for &b in &block[i + 1..] {
+                visit(a, b);
+            }
+        }
+    });
+
+    // Circle method: block 0 stays in place while the rest rotate. With an odd
+    // number of blocks, the extra slot is a bye.
+    let slots = blocks.len() + blocks.len() % 2;
+    for round in 0..slots - 1 {
+        let slot_block = |slot: usize| match slot {
+            0 => 0,
+            _ => (slot - 1 + round) % (slots - 1) + 1,
+        };
+        let matches: Vec<(usize, usize)> = (0..slots / 2)
+            .map(|slot| (slot_block(slot), slot_block(slots - 1 - slot)))
+            .filter(|&(a, b)| a < blocks.len() && b < blocks.len())
+            .map(|(a, b)| (a.min(b), a.max(b)))
+            .collect();
+
+        matches.into_par_iter().for_each(|(a, b)| {
+            for &index_a in blocks[a] {
+                for &index_b in blocks[b] {
+                    visit(index_a, index_b);
+                }
+            }
+        });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,55 @@
+use crate::{
+    prelude::*,
+    mutable::read_write::ParSelectReadWriteIter,
+};
+use rayon::{
+    prelude::*,
+    iter::plumbing::{ Consumer, UnindexedConsumer, ProducerCallback },
+};
+use force_send_sync::Sync as ForceSync;
+
+impl<'a, Data, Reads, Writes, Idx> ParallelIterator for ParSelectReadWriteIter<'a, Data, Reads, Writes>
+where
+    Data: Send + Sync + OneToOne<Idx>,
+    Data::Output: 'a + Send + Sync,
+    Reads: IndexedParallelIterator<Item = Idx>,
+    Writes: IndexedParallelIterator<Item = Idx>,
+{
+    type Item = (&'a Data::Output, &'a mut Data::Output);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>
+    {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        self.reads.zip(self.writes).map(|(read, write)| {
+            unsafe { ((*ptr).as_ref().unwrap().index(read), (*ptr).as_mut().unwrap().index_mut(write)) }
+        }).drive_unindexed(consumer)
+    }
+}
+
+impl<'a, Data, Reads, Writes, Idx> IndexedParallelIterator for ParSelectReadWriteIter<'a, Data, Reads, Writes>
+where
+    Data: Send + Sync + OneToOne<Idx>,
+    Data::Output: 'a + Send + Sync,
+    Reads: IndexedParallelIterator<Item = Idx>,
+    Writes: IndexedParallelIterator<Item = Idx>,
+{
+    fn len(&self) -> usize {
+        self.reads.len().min(self.writes.len())
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        self.reads.zip(self.writes).map(|(read, write)| {
+            unsafe { ((*ptr).as_ref().unwrap().index(read), (*ptr).as_mut().unwrap().index_mut(write)) }
+        }).drive(consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        let ptr: ForceSync<*mut _> = unsafe { ForceSync::new(self.data) };
+        self.reads.zip(self.writes).map(|(read, write)| {
+            unsafe { ((*ptr).as_ref().unwrap().index(read), (*ptr).as_mut().unwrap().index_mut(write)) }
+        }).with_producer(callback)
+    }
+}
\ No newline at end of file
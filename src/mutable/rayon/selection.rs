@@ -0,0 +1,50 @@
+use std::cmp::Ordering;
+use crate::{
+    prelude::*,
+    mutable::selection::SelectionMut,
+};
+use rayon::prelude::*;
+
+impl<'a, Data, Idx> SelectionMut<'a, Data, Idx>
+where
+    Data: ?Sized + OneToOne<Idx> + Sync,
+    Data::Output: Sized,
+    Idx: Copy + Sync,
+{
+    /// Sorts the selected elements in parallel.
+    ///
+    /// Parallel form of [`sort`](SelectionMut::sort). The comparisons happen in
+    /// parallel, but the elements are moved into place on the calling thread.
+    pub fn par_sort(&mut self)
+    where
+        Data::Output: Ord,
+    {
+        self.par_sort_by(Ord::cmp)
+    }
+
+    /// Sorts the selected elements in parallel with a comparator function.
+    ///
+    /// Parallel form of [`sort_by`](SelectionMut::sort_by).
+    pub fn par_sort_by<F>(&mut self, compare: F)
+    where
+        F: Fn(&Data::Output, &Data::Output) -> Ordering + Sync,
+    {
+        let mut order: Vec<usize> = (0..self.len()).collect();
+        {
+            let view = &*self;
+            order.par_sort_by(|&a, &b| compare(&view[a], &view[b]));
+        }
+        self.permute(order);
+    }
+
+    /// Sorts the selected elements in parallel with a key extraction function.
+    ///
+    /// Parallel form of [`sort_by_key`](SelectionMut::sort_by_key).
+    pub fn par_sort_by_key<K, F>(&mut self, f: F)
+    where
+        F: Fn(&Data::Output) -> K + Sync,
+        K: Ord,
+    {
+        self.par_sort_by(|a, b| f(a).cmp(&f(b)))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,55 @@
+use crate::mutable::zip::{ ZipSource, ParSelectZipIter };
+use rayon::{
+    prelude::*,
+    iter::plumbing::{ Consumer, UnindexedConsumer, ProducerCallback },
+};
+use force_send_sync::Sync as ForceSync;
+
+impl<'a, Sources, Indices> ParallelIterator for ParSelectZipIter<'a, Sources, Indices>
+where
+    Sources: ZipSource<'a, Indices::Item> + Send,
+    Sources::Item: Send,
+    Indices: ParallelIterator,
+    Indices::Item: Copy,
+{
+    type Item = Sources::Item;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>
+    {
+        let mut sources = self.sources;
+        let sources = unsafe { ForceSync::new(sources.as_ptr()) };
+        self.indices.map(|index| {
+            unsafe { Sources::fetch(*sources, index) }
+        }).drive_unindexed(consumer)
+    }
+}
+
+impl<'a, Sources, Indices> IndexedParallelIterator for ParSelectZipIter<'a, Sources, Indices>
+where
+    Sources: ZipSource<'a, Indices::Item> + Send,
+    Sources::Item: Send,
+    Indices: IndexedParallelIterator,
+    Indices::Item: Copy,
+{
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        let mut sources = self.sources;
+        let sources = unsafe { ForceSync::new(sources.as_ptr()) };
+        self.indices.map(|index| {
+            unsafe { Sources::fetch(*sources, index) }
+        }).drive(consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        let mut sources = self.sources;
+        let sources = unsafe { ForceSync::new(sources.as_ptr()) };
+        self.indices.map(|index| {
+            unsafe { Sources::fetch(*sources, index) }
+        }).with_producer(callback)
+    }
+}
\ No newline at end of file
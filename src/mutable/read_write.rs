@@ -0,0 +1,92 @@
+use std::{
+    marker::PhantomData,
+    hash::Hash,
+};
+use crate::{
+    prelude::*,
+    iter_type::Sequential,
+    mutable::validate::unique_set,
+};
+#[cfg(feature = "rayon")]
+use crate::iter_type::Parallel;
+
+/// Iterates, with a slice of read indices and a slice of write indices, through a
+/// [`OneToOne`] collection, producing each read element immutably alongside the
+/// matching write element mutably.
+pub struct SelectReadWriteIter<'a, Data, Reads, Writes, IterType>
+where
+    Data: ?Sized,
+{
+    pub(crate) data: &'a mut Data,
+    pub(crate) reads: Reads,
+    pub(crate) writes: Writes,
+    pub(crate) _phantom: PhantomData<IterType>,
+}
+
+/// Return type for [`select_read_write`](crate::SelectIndicesMut::select_read_write).
+pub type SeqSelectReadWriteIter<'a, Data, Reads, Writes> = SelectReadWriteIter<'a, Data, Reads, Writes, Sequential>;
+#[cfg(feature = "rayon")]
+/// Return type for [`par_select_read_write`](crate::ParSelectIndicesMut::par_select_read_write).
+pub type ParSelectReadWriteIter<'a, Data, Reads, Writes> = SelectReadWriteIter<'a, Data, Reads, Writes, Parallel>;
+
+/// Panics if the slices have different lengths, if the writes contain a duplicate,
+/// or if any read is also a write.
+pub(crate) fn assert_read_write<Idx>(reads: &[Idx], writes: &[Idx], caller: &str)
+where
+    Idx: Eq + Hash + Copy,
+{
+    assert!(
+        reads.len() == writes.len(),
+        "{} was passed {} reads and {} writes!", caller, reads.len(), writes.len(),
+    );
+    let write_set = unique_set(writes, caller);
+    assert!(
+        !reads.iter().any(|read| write_set.contains(read)),
+        "{} was passed a read index that is also a write index!", caller,
+    );
+}
+
+impl<'a, Data, Reads, Writes, Idx> Iterator for SeqSelectReadWriteIter<'a, Data, Reads, Writes>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Data::Output: 'a,
+    Reads: Iterator<Item = Idx>,
+    Writes: Iterator<Item = Idx>,
+{
+    type Item = (&'a Data::Output, &'a mut Data::Output);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (read, write) = (self.reads.next()?, self.writes.next()?);
+        let ptr: *mut Data = self.data;
+
+        // Safety: The writes are unique, and no write is a read, so the
+        // mutable element is never aliased by another reference.
+        unsafe { Some((ptr.as_ref().unwrap().index(read), ptr.as_mut().unwrap().index_mut(write))) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.writes.size_hint()
+    }
+}
+
+impl<'a, Data, Reads, Writes, Idx> DoubleEndedIterator for SeqSelectReadWriteIter<'a, Data, Reads, Writes>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Data::Output: 'a,
+    Reads: DoubleEndedIterator<Item = Idx>,
+    Writes: DoubleEndedIterator<Item = Idx>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (read, write) = (self.reads.next_back()?, self.writes.next_back()?);
+        let ptr: *mut Data = self.data;
+        unsafe { Some((ptr.as_ref().unwrap().index(read), ptr.as_mut().unwrap().index_mut(write))) }
+    }
+}
+
+impl<'a, Data, Reads, Writes, Idx> ExactSizeIterator for SeqSelectReadWriteIter<'a, Data, Reads, Writes>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Data::Output: 'a,
+    Reads: ExactSizeIterator<Item = Idx>,
+    Writes: ExactSizeIterator<Item = Idx>,
+{}
\ No newline at end of file
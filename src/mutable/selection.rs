@@ -0,0 +1,263 @@
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    ops::{ Index, IndexMut },
+    slice::Iter,
+    iter::Copied,
+    ptr,
+};
+use crate::{
+    prelude::*,
+    indexed_type::Unindexed,
+    immutable::iter::SeqSelectIndicesIter,
+    mutable::{
+        iter::SeqSelectIndicesUncheckedMutIter,
+        validate::assert_unique,
+    },
+};
+
+/// A mutable view of the selected elements of a [`OneToOne`] collection that
+/// behaves like a slice.
+///
+/// `view[k]` is the element at the `k`-th selected index. Methods that reorder
+/// the view, such as [`swap`](SelectionMut::swap) or [`sort`](SelectionMut::sort),
+/// move the underlying elements between the selected positions.
+///
+/// Return type for [`selection_mut`](crate::SelectIndicesMut::selection_mut).
+pub struct SelectionMut<'a, Data, Idx>
+where
+    Data: ?Sized,
+    Idx: Clone,
+{
+    pub(crate) data: &'a mut Data,
+    pub(crate) indices: Cow<'a, [Idx]>,
+}
+
+impl<'a, Data, Idx> SelectionMut<'a, Data, Idx>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Idx: Copy,
+{
+    /// Returns the number of selected elements.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns `true` if no elements are selected.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns the indices of the selected elements in the underlying collection.
+    pub fn indices(&self) -> &[Idx] {
+        &self.indices
+    }
+
+    /// Returns the `k`-th selected element, or `None` if `k` is out of bounds.
+    pub fn get(&self, k: usize) -> Option<&Data::Output> {
+        self.indices.get(k).map(|&index| &self.data[index])
+    }
+
+    /// Returns the `k`-th selected element mutably, or `None` if `k` is out of bounds.
+    pub fn get_mut(&mut self, k: usize) -> Option<&mut Data::Output> {
+        match self.indices.get(k) {
+            Some(&index) => Some(&mut self.data[index]),
+            None => None,
+        }
+    }
+
+    /// Creates a view of the given positions of this view, so that `narrowed[k]`
+    /// is `self[sub_indices[k]]`.
+    ///
+    /// Only `sub_indices` is checked for uniqueness, since the indices of this
+    /// view are already unique.
+    pub fn select(&mut self, sub_indices: &[usize]) -> SelectionMut<'_, Data, Idx> {
+        assert_unique(sub_indices, "select");
+
+        let indices: Vec<Idx> = sub_indices.iter().map(|&k| self.indices[k]).collect();
+        SelectionMut {
+            data: &mut *self.data,
+            indices: indices.into(),
+        }
+    }
+
+    /// Iterates through the selected elements.
+    pub fn iter(&self) -> SeqSelectIndicesIter<'_, Data, Copied<Iter<'_, Idx>>, Unindexed> {
+        (*self.data).select_indices(&self.indices)
+    }
+
+    /// Mutably iterates through the selected elements.
+    pub fn iter_mut(&mut self) -> SeqSelectIndicesUncheckedMutIter<'_, Data, Copied<Iter<'_, Idx>>, Unindexed> {
+        // Safety: The indices were checked for uniqueness when the view was created.
+        unsafe { self.data.select_indices_mut_unchecked(&self.indices) }
+    }
+}
+
+impl<'a, Data, Idx> SelectionMut<'a, Data, Idx>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Data::Output: Sized,
+    Idx: Copy,
+{
+    /// Swaps the `a`-th and `b`-th selected elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let (index_a, index_b) = (self.indices[a], self.indices[b]);
+        if a != b {
+            let ptr_a: *mut Data::Output = &mut self.data[index_a];
+            let ptr_b: *mut Data::Output = &mut self.data[index_b];
+
+            // Safety: The indices are unique, so the two elements do not overlap.
+            unsafe { ptr::swap(ptr_a, ptr_b) }
+        }
+    }
+
+    /// Reverses the order of the selected elements.
+    pub fn reverse(&mut self) {
+        let len = self.len();
+        for k in 0..len / 2 {
+            self.swap(k, len - 1 - k);
+        }
+    }
+
+    /// Rotates the selected elements so that the `mid`-th selected element
+    /// becomes the first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than [`len`](SelectionMut::len).
+    pub fn rotate_left(&mut self, mid: usize) {
+        let len = self.len();
+        assert!(mid <= len, "rotate_left was passed mid {} for a selection of len {}", mid, len);
+        self.permute((0..len).map(|k| (k + mid) % len).collect());
+    }
+
+    /// Rotates the selected elements so that the `len - k`-th selected element
+    /// becomes the first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than [`len`](SelectionMut::len).
+    pub fn rotate_right(&mut self, k: usize) {
+        let len = self.len();
+        assert!(k <= len, "rotate_right was passed k {} for a selection of len {}", k, len);
+        self.rotate_left(len - k);
+    }
+
+    /// Sorts the selected elements.
+    ///
+    /// This sort is stable, and does not require the elements to implement [`Clone`].
+    pub fn sort(&mut self)
+    where
+        Data::Output: Ord,
+    {
+        self.sort_by(Ord::cmp)
+    }
+
+    /// Sorts the selected elements with a comparator function.
+    ///
+    /// This sort is stable, and does not require the elements to implement [`Clone`].
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&Data::Output, &Data::Output) -> Ordering,
+    {
+        let mut order: Vec<usize> = (0..self.len()).collect();
+        order.sort_by(|&a, &b| compare(&self[a], &self[b]));
+        self.permute(order);
+    }
+
+    /// Sorts the selected elements with a key extraction function.
+    ///
+    /// This sort is stable, and does not require the elements to implement [`Clone`].
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&Data::Output) -> K,
+        K: Ord,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Binary searches the selected elements for a given element.
+    ///
+    /// See [`slice::binary_search`] for details. The selected elements
+    /// should already be sorted.
+    pub fn binary_search(&self, x: &Data::Output) -> Result<usize, usize>
+    where
+        Data::Output: Ord,
+    {
+        self.binary_search_by(|elem| elem.cmp(x))
+    }
+
+    /// Binary searches the selected elements with a comparator function.
+    ///
+    /// See [`slice::binary_search_by`] for details. The selected elements
+    /// should already be sorted.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&Data::Output) -> Ordering,
+    {
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match f(&self[mid]) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    /// Moves the elements so that the `k`-th selected element becomes
+    /// the `order[k]`-th element from before the call.
+    pub(crate) fn permute(&mut self, order: Vec<usize>) {
+        let mut placed = vec![false; order.len()];
+        for start in 0..order.len() {
+            let mut k = start;
+            while !placed[k] {
+                placed[k] = true;
+                let next = order[k];
+                if next == start {
+                    break;
+                }
+                self.swap(k, next);
+                k = next;
+            }
+        }
+    }
+}
+
+// Safety: Every selected index is unique, so every position in the view
+// refers to a different element of the underlying OneToOne collection.
+unsafe impl<'a, Data, Idx> OneToOne<usize> for SelectionMut<'a, Data, Idx>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Idx: Copy,
+{}
+
+impl<'a, Data, Idx> Index<usize> for SelectionMut<'a, Data, Idx>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Idx: Copy,
+{
+    type Output = Data::Output;
+
+    fn index(&self, k: usize) -> &Self::Output {
+        &self.data[self.indices[k]]
+    }
+}
+
+impl<'a, Data, Idx> IndexMut<usize> for SelectionMut<'a, Data, Idx>
+where
+    Data: ?Sized + OneToOne<Idx>,
+    Idx: Copy,
+{
+    fn index_mut(&mut self, k: usize) -> &mut Self::Output {
+        let index = self.indices[k];
+        &mut self.data[index]
+    }
+}
\ No newline at end of file
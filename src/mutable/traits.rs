@@ -1,14 +1,27 @@
 use std::{
-    ops::IndexMut,
+    ops::{ Index, IndexMut },
     hash::Hash,
     collections::HashSet,
     slice::Iter,
     iter::Copied,
+    vec::IntoIter,
+    cmp::Ordering,
+    mem,
 };
 use crate::{
     indexed_type::Unindexed,
-    mutable::iter::{
-        SeqSelectIndicesMutIter, SeqSelectIndicesUncheckedMutIter,
+    mutable::{
+        iter::{ SeqSelectIndicesMutIter, SeqSelectIndicesUncheckedMutIter },
+        selection::SelectionMut,
+        neighbors::SeqSelectNeighborsMutIter,
+        pairs::{ SelectPairsMutIter, assert_disjoint_pairs },
+        csr::{ SeqSelectCsrMutIter, assert_csr_frontier },
+        family::{ Children, assert_disjoint_families },
+        disjoint::DisjointMut,
+        read_write::{ SeqSelectReadWriteIter, assert_read_write },
+        groups::SeqSelectGroupsMutIter,
+        unique::{ UniqueIndexIter, IndexSet },
+        validate::{ assert_unique, unique_set, assert_disjoint_groups },
     },
 };
 
@@ -22,12 +35,12 @@ use crate::{
 /// 
 /// 1. For every valid index, there is only one output.
 /// 2. Invalid indices should panic and not return multiple references
-///     to one object.
+///    to one object.
 /// 3. When mutably indexed, the type never reads any other
-///     indexable objects ([`HashMap`s](std::collections::HashMap) are not `OneToOne` because of this).
+///    indexable objects ([`HashMap`s](std::collections::HashMap) are not `OneToOne` because of this).
 /// 4. When mutably indexed, the type does not mutate itself. It
-///     should only return a mutable index to an object held by the
-///     type.
+///    should only return a mutable index to an object held by the
+///    type.
 pub unsafe trait OneToOne<Idx> : IndexMut<Idx> {}
 
 unsafe impl<T> OneToOne<usize> for [T] {}
@@ -68,7 +81,7 @@ pub trait SelectIndicesMut<'a>
     /// violate [`OneToOne`] guarantees with the given collection.
     /// Otherwise, undefined behavior will occur and XOR mutability
     /// will be violated.
-    unsafe fn select_with_iter_mut_unchecked<Indices>(&'a mut self, indices: Indices) -> SeqSelectIndicesUncheckedMutIter<Self, Indices::IntoIter, Unindexed>
+    unsafe fn select_with_iter_mut_unchecked<Indices>(&'a mut self, indices: Indices) -> SeqSelectIndicesUncheckedMutIter<'a, Self, Indices::IntoIter, Unindexed>
     where
         Indices: IntoIterator,
         Indices::Item: Copy,
@@ -95,7 +108,7 @@ pub trait SelectIndicesMut<'a>
     /// violate [`OneToOne`] guarantees with the given collection.
     /// Otherwise, undefined behavior will occur and XOR mutability
     /// will be violated.
-    unsafe fn select_indices_mut_unchecked<Idx>(&'a mut self, indices: &'a [Idx]) -> SeqSelectIndicesUncheckedMutIter<Self, Copied<Iter<'a, Idx>>, Unindexed>
+    unsafe fn select_indices_mut_unchecked<Idx>(&'a mut self, indices: &'a [Idx]) -> SeqSelectIndicesUncheckedMutIter<'a, Self, Copied<Iter<'a, Idx>>, Unindexed>
     where
         Self:IndexMut<Idx>,
         Idx: Copy,
@@ -117,22 +130,471 @@ pub trait SelectIndicesMut<'a>
     /// but the resulting iterator is slightly faster, because it does
     /// not need to check for duplicate indices at every index. Overall,
     /// this method is slightly faster.
-    fn select_indices_mut<Idx>(&'a mut self, indices: &'a [Idx]) -> SeqSelectIndicesUncheckedMutIter<Self, Copied<Iter<'a, Idx>>, Unindexed>
+    fn select_indices_mut<Idx>(&'a mut self, indices: &'a [Idx]) -> SeqSelectIndicesUncheckedMutIter<'a, Self, Copied<Iter<'a, Idx>>, Unindexed>
     where
         Self: OneToOne<Idx>,
         Idx: Sized + Eq + Hash + Copy,
     {
-        {
-            let index_check: HashSet<Idx> = indices.iter().copied().collect();
-            assert!(
-                index_check.len() == indices.len(),
-                "par_select_indices_mut was passed duplicate indices!",
-            )
-        }
+        assert_unique(indices, "select_indices_mut");
 
         unsafe { self.select_with_iter_mut_unchecked(indices.iter().copied()) }
     }
+
+    /// Iterate through a collection with an owned list of indices.
+    /// 
+    /// Unlike [`select_indices_mut`](SelectIndicesMut::select_indices_mut), the returned
+    /// iterator only borrows the collection, so it can be returned from a function
+    /// that builds its own list of indices.
+    /// 
+    /// # Performance
+    /// 
+    /// Like [`select_indices_mut`](SelectIndicesMut::select_indices_mut), this method
+    /// checks that all indices are unique once before returning the iterator.
+    fn select_owned_indices_mut<Idx>(&'a mut self, indices: Vec<Idx>) -> SeqSelectIndicesUncheckedMutIter<'a, Self, IntoIter<Idx>, Unindexed>
+    where
+        Self: OneToOne<Idx>,
+        Idx: Sized + Eq + Hash + Copy,
+    {
+        assert_unique(&indices, "select_owned_indices_mut");
+
+        unsafe { self.select_with_iter_mut_unchecked(indices) }
+    }
     
+    /// Create a [`SelectionMut`] view of the elements at a slice of indices.
+    /// 
+    /// Unlike the iterator returned by [`select_indices_mut`](SelectIndicesMut::select_indices_mut),
+    /// the view can be indexed, iterated more than once, and reordered in place.
+    /// The indices are checked for uniqueness once when the view is created.
+    fn selection_mut<Idx>(&'a mut self, indices: &'a [Idx]) -> SelectionMut<'a, Self, Idx>
+    where
+        Self: OneToOne<Idx>,
+        Idx: Sized + Eq + Hash + Copy,
+    {
+        assert_unique(indices, "selection_mut");
+
+        SelectionMut {
+            data: self,
+            indices: indices.into(),
+        }
+    }
+
+    /// Create a [`DisjointMut`] handle, which hands out mutable references to
+    /// elements one index at a time.
+    /// 
+    /// Unlike the other methods of this trait, the indices do not need to be known
+    /// up front. Instead, each call to [`get_mut`](DisjointMut::get_mut) returns an
+    /// error if its element was already handed out.
+    fn disjoint_mut<Idx>(&'a mut self) -> DisjointMut<'a, Self, Idx>
+    where
+        Self: OneToOne<Idx>,
+        Idx: Eq + Hash + Copy,
+    {
+        DisjointMut {
+            data: self,
+            borrowed: HashSet::new(),
+        }
+    }
+
+    /// Split a collection into several selections at once, one for each group
+    /// of indices.
+    /// 
+    /// Each iterator borrows the collection independently, so they can be used in
+    /// any order, or moved to different threads. This method panics if any index
+    /// appears more than once, whether in the same group or in different groups.
+    /// 
+    /// ```rust
+    /// use select_indices::prelude::*;
+    /// 
+    /// let mut data = [0; 6];
+    /// let groups = [vec![0, 2], vec![5, 1]];
+    /// 
+    /// let mut selections = data.split_selections_mut(&groups);
+    /// let evens = selections.remove(0);
+    /// let others = selections.remove(0);
+    /// others.for_each(|x| *x = 2);
+    /// evens.for_each(|x| *x = 1);
+    /// 
+    /// assert_eq!(data, [1, 2, 1, 0, 0, 2]);
+    /// ```
+    fn split_selections_mut<Idx, G>(&'a mut self, groups: &'a [G]) -> Vec<SeqSelectIndicesUncheckedMutIter<'a, Self, Copied<Iter<'a, Idx>>, Unindexed>>
+    where
+        Self: OneToOne<Idx>,
+        Idx: Sized + Eq + Hash + Copy,
+        G: AsRef<[Idx]>,
+    {
+        assert_disjoint_groups(groups, "split_selections_mut");
+
+        let ptr: *mut Self = self;
+        // Safety: No index appears in more than one group, so no two
+        // iterators can produce the same element.
+        groups.iter()
+            .map(|group| unsafe { ptr.as_mut().unwrap().select_indices_mut_unchecked(group.as_ref()) })
+            .collect()
+    }
+
+    /// Iterate through a collection one group of indices at a time, producing
+    /// a mutable iterator over the elements of each group.
+    /// 
+    /// Groups are checked the same way as
+    /// [`split_selections_mut`](SelectIndicesMut::split_selections_mut), but their
+    /// iterators are produced one at a time instead of all at once.
+    /// 
+    /// ```rust
+    /// use select_indices::prelude::*;
+    /// 
+    /// let mut data = [1, 2, 3, 4, 5];
+    /// let clusters = [vec![0, 4], vec![1, 2, 3]];
+    /// 
+    /// data.select_groups_mut(&clusters).for_each(|cluster| {
+    ///     let mut cluster: Vec<&mut i32> = cluster.collect();
+    ///     let total: i32 = cluster.iter().map(|x| **x).sum();
+    ///     cluster.iter_mut().for_each(|x| **x = total);
+    /// });
+    /// 
+    /// assert_eq!(data, [6, 9, 9, 9, 6]);
+    /// ```
+    fn select_groups_mut<Idx, G>(&'a mut self, groups: &'a [G]) -> SeqSelectGroupsMutIter<'a, Self, Iter<'a, G>, Idx>
+    where
+        Self: OneToOne<Idx>,
+        Idx: Sized + Eq + Hash + Copy,
+        G: AsRef<[Idx]>,
+    {
+        assert_disjoint_groups(groups, "select_groups_mut");
+
+        SeqSelectGroupsMutIter {
+            data: self,
+            groups: groups.iter(),
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Sort the elements at a slice of indices among themselves, so that
+    /// `self[indices[0]]` holds the smallest of them.
+    /// 
+    /// This sort is stable, and does not require the elements to implement [`Clone`].
+    /// See [`SelectionMut::sort`].
+    fn sort_selected<Idx>(&'a mut self, indices: &'a [Idx])
+    where
+        Self: OneToOne<Idx>,
+        <Self as Index<Idx>>::Output: Sized + Ord,
+        Idx: Sized + Eq + Hash + Copy,
+    {
+        self.selection_mut(indices).sort()
+    }
+
+    /// Sort the elements at a slice of indices among themselves with a
+    /// comparator function.
+    /// 
+    /// See [`SelectionMut::sort_by`].
+    fn sort_selected_by<Idx, F>(&'a mut self, indices: &'a [Idx], compare: F)
+    where
+        Self: OneToOne<Idx>,
+        <Self as Index<Idx>>::Output: Sized,
+        Idx: Sized + Eq + Hash + Copy,
+        F: FnMut(&<Self as Index<Idx>>::Output, &<Self as Index<Idx>>::Output) -> Ordering,
+    {
+        self.selection_mut(indices).sort_by(compare)
+    }
+
+    /// Sort the elements at a slice of indices among themselves with a key
+    /// extraction function.
+    /// 
+    /// See [`SelectionMut::sort_by_key`].
+    fn sort_selected_by_key<Idx, K, F>(&'a mut self, indices: &'a [Idx], f: F)
+    where
+        Self: OneToOne<Idx>,
+        <Self as Index<Idx>>::Output: Sized,
+        Idx: Sized + Eq + Hash + Copy,
+        F: FnMut(&<Self as Index<Idx>>::Output) -> K,
+        K: Ord,
+    {
+        self.selection_mut(indices).sort_by_key(f)
+    }
+
+    /// Reverse the order of the elements at a slice of indices.
+    /// 
+    /// See [`SelectionMut::reverse`].
+    fn reverse_selected<Idx>(&'a mut self, indices: &'a [Idx])
+    where
+        Self: OneToOne<Idx>,
+        <Self as Index<Idx>>::Output: Sized,
+        Idx: Sized + Eq + Hash + Copy,
+    {
+        self.selection_mut(indices).reverse()
+    }
+
+    /// Rotate the elements at a slice of indices so that the element at
+    /// `indices[mid]` moves to `indices[0]`.
+    /// 
+    /// See [`SelectionMut::rotate_left`].
+    fn rotate_selected<Idx>(&'a mut self, indices: &'a [Idx], mid: usize)
+    where
+        Self: OneToOne<Idx>,
+        <Self as Index<Idx>>::Output: Sized,
+        Idx: Sized + Eq + Hash + Copy,
+    {
+        self.selection_mut(indices).rotate_left(mid)
+    }
+
+    /// Swap the element at each index in `a_indices` with the element at the
+    /// matching index in `b_indices`.
+    /// 
+    /// This method panics if the two slices have different lengths, or if any
+    /// index appears more than once across both slices.
+    fn swap_selections<Idx>(&'a mut self, a_indices: &'a [Idx], b_indices: &'a [Idx])
+    where
+        Self: OneToOne<Idx>,
+        <Self as Index<Idx>>::Output: Sized,
+        Idx: Sized + Eq + Hash + Copy,
+    {
+        assert!(
+            a_indices.len() == b_indices.len(),
+            "swap_selections was passed {} and {} indices!", a_indices.len(), b_indices.len(),
+        );
+        let indices: Vec<Idx> = a_indices.iter().chain(b_indices).copied().collect();
+        assert_unique(&indices, "swap_selections");
+
+        let len = a_indices.len();
+        let mut view = SelectionMut {
+            data: self,
+            indices: indices.into(),
+        };
+        for k in 0..len {
+            view.swap(k, k + len);
+        }
+    }
+
+    /// Swap the element at each index in `indices` with the element at the
+    /// matching index of another collection.
+    /// 
+    /// Both slices of indices are checked the same way as
+    /// [`select_indices_mut`](SelectIndicesMut::select_indices_mut). This method
+    /// also panics if the two slices have different lengths.
+    fn swap_between<Idx, Other, OtherIdx>(&'a mut self, indices: &'a [Idx], other: &'a mut Other, other_indices: &'a [OtherIdx])
+    where
+        Self: OneToOne<Idx>,
+        <Self as Index<Idx>>::Output: Sized,
+        Idx: Sized + Eq + Hash + Copy,
+        Other: ?Sized + OneToOne<OtherIdx, Output = <Self as Index<Idx>>::Output>,
+        OtherIdx: Sized + Eq + Hash + Copy,
+    {
+        assert!(
+            indices.len() == other_indices.len(),
+            "swap_between was passed {} and {} indices!", indices.len(), other_indices.len(),
+        );
+
+        self.select_indices_mut(indices)
+            .zip(other.select_indices_mut(other_indices))
+            .for_each(|(a, b)| mem::swap(a, b));
+    }
+
+    /// Iterate through a collection with a slice of centre indices, producing each
+    /// centre mutably alongside an iterator over its neighbours.
+    /// 
+    /// `neighbor_fn` is called with each centre index and returns the indices of
+    /// its neighbours. Neighbours are only read, so they may be shared between centres.
+    /// 
+    /// This method panics if the centres contain a duplicate, and the iterator
+    /// panics if any centre is also returned as a neighbour, including its own.
+    /// 
+    /// ```rust
+    /// use select_indices::prelude::*;
+    /// 
+    /// let mut cells = [1, 2, 3, 4, 5];
+    /// 
+    /// cells.select_with_neighbors_mut(&[1, 3], |i| vec![i - 1, i + 1])
+    ///     .for_each(|(cell, neighbors)| *cell = neighbors.sum());
+    /// 
+    /// assert_eq!(cells, [1, 4, 3, 8, 5]);
+    /// ```
+    fn select_with_neighbors_mut<Idx, NeighborFn, N>(&'a mut self, centers: &'a [Idx], neighbor_fn: NeighborFn) -> SeqSelectNeighborsMutIter<'a, Self, Copied<Iter<'a, Idx>>, Idx, NeighborFn>
+    where
+        Self: OneToOne<Idx>,
+        Idx: Sized + Eq + Hash + Copy,
+        NeighborFn: FnMut(Idx) -> N,
+        N: IntoIterator<Item = Idx>,
+    {
+        SeqSelectNeighborsMutIter {
+            center_set: unique_set(centers, "select_with_neighbors_mut"),
+            data: self,
+            centers: centers.iter().copied(),
+            neighbor_fn,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Iterate through the node data of a graph with a slice of frontier nodes,
+    /// producing each frontier node mutably alongside an iterator over its neighbours.
+    /// 
+    /// The graph is given in compressed sparse row form: the neighbours of node `i`
+    /// are `targets[offsets[i]..offsets[i + 1]]`. Neighbours are only read, so they
+    /// may be shared between frontier nodes.
+    /// 
+    /// This method panics if the frontier contains a duplicate, or if any frontier
+    /// node is a neighbour of a frontier node, including itself. The whole frontier
+    /// is checked before iteration starts.
+    /// 
+    /// ```rust
+    /// use select_indices::prelude::*;
+    /// 
+    /// // 0 -> 1, 0 -> 2, 3 -> 2
+    /// let offsets = [0, 2, 2, 2, 3];
+    /// let targets = [1, 2, 2];
+    /// let mut nodes = [0, 10, 20, 30];
+    /// 
+    /// nodes.select_csr_mut(&offsets, &targets, &[0, 3])
+    ///     .for_each(|(node, neighbors)| *node += neighbors.sum::<i32>());
+    /// 
+    /// assert_eq!(nodes, [30, 10, 20, 50]);
+    /// ```
+    fn select_csr_mut(&'a mut self, offsets: &'a [usize], targets: &'a [usize], frontier: &'a [usize]) -> SeqSelectCsrMutIter<'a, Self, Copied<Iter<'a, usize>>>
+    where
+        Self: OneToOne<usize>,
+    {
+        assert_csr_frontier(offsets, targets, frontier, "select_csr_mut");
+
+        SeqSelectCsrMutIter {
+            data: self,
+            offsets,
+            targets,
+            frontier: frontier.iter().copied(),
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Select one parent index and a slice of child indices at the same time,
+    /// producing the parent mutably alongside a mutable iterator over the children.
+    /// 
+    /// This method panics if the parent is one of the children, or if the
+    /// children contain a duplicate.
+    /// 
+    /// ```rust
+    /// use select_indices::prelude::*;
+    /// 
+    /// let mut offsets = [1, 10, 20, 30];
+    /// 
+    /// let (parent, children) = offsets.select_one_and_many_mut(0, &[3, 1]);
+    /// children.for_each(|child| *child += *parent);
+    /// *parent = 0;
+    /// 
+    /// assert_eq!(offsets, [0, 11, 20, 31]);
+    /// ```
+    fn select_one_and_many_mut<Idx>(&'a mut self, parent: Idx, children: &'a [Idx]) -> (&'a mut <Self as Index<Idx>>::Output, Children<'a, Self, Idx>)
+    where
+        Self: OneToOne<Idx>,
+        Idx: Sized + Eq + Hash + Copy,
+    {
+        assert_disjoint_families(&[(parent, children)], "select_one_and_many_mut");
+
+        let ptr: *mut Self = self;
+        // Safety: The children are unique, and the parent is not one of them.
+        unsafe {
+            let parent = ptr.as_mut().unwrap().index_mut(parent);
+            (parent, ptr.as_mut().unwrap().select_indices_mut_unchecked(children))
+        }
+    }
+
+    /// Iterate through a collection with a slice of read indices and a slice of
+    /// write indices, producing each read element immutably alongside the write
+    /// element at the same position.
+    /// 
+    /// Reads may repeat, but this method panics if the writes contain a duplicate,
+    /// if any read is also a write, or if the slices have different lengths.
+    /// 
+    /// ```rust
+    /// use select_indices::prelude::*;
+    /// 
+    /// let mut data = [1, 2, 0, 0, 0];
+    /// 
+    /// data.select_read_write(&[0, 1, 0], &[2, 3, 4])
+    ///     .for_each(|(input, output)| *output = input * 10);
+    /// 
+    /// assert_eq!(data, [1, 2, 10, 20, 10]);
+    /// ```
+    fn select_read_write<Idx>(&'a mut self, reads: &'a [Idx], writes: &'a [Idx]) -> SeqSelectReadWriteIter<'a, Self, Copied<Iter<'a, Idx>>, Copied<Iter<'a, Idx>>>
+    where
+        Self: OneToOne<Idx>,
+        Idx: Sized + Eq + Hash + Copy,
+    {
+        assert_read_write(reads, writes, "select_read_write");
+
+        SeqSelectReadWriteIter {
+            data: self,
+            reads: reads.iter().copied(),
+            writes: writes.iter().copied(),
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Iterate through a collection with a slice of index pairs, producing
+    /// both elements of each pair mutably.
+    /// 
+    /// This method panics if a pair connects an index to itself, or if any
+    /// index appears more than once across all pairs. For pairs that share
+    /// indices, see [`par_select_pairs_mut`](crate::ParSelectIndicesMut::par_select_pairs_mut).
+    fn select_pairs_mut<Idx>(&'a mut self, pairs: &'a [(Idx, Idx)]) -> SelectPairsMutIter<'a, Self, Copied<Iter<'a, (Idx, Idx)>>>
+    where
+        Self: OneToOne<Idx>,
+        Idx: Sized + Eq + Hash + Copy,
+    {
+        assert_disjoint_pairs(pairs, "select_pairs_mut");
+
+        SelectPairsMutIter {
+            data: self,
+            pairs: pairs.iter().copied(),
+        }
+    }
+
+    /// Call `op` on every unordered pair of elements at a slice of indices.
+    /// 
+    /// For every `i < j`, `op` is called once with the elements at `indices[i]`
+    /// and `indices[j]`, in that order. Only one pair is borrowed at a time.
+    /// The indices are checked the same way as
+    /// [`select_indices_mut`](SelectIndicesMut::select_indices_mut).
+    fn for_each_pair_mut<Idx, Op>(&'a mut self, indices: &'a [Idx], mut op: Op)
+    where
+        Self: OneToOne<Idx>,
+        Idx: Sized + Eq + Hash + Copy,
+        Op: FnMut(&mut <Self as Index<Idx>>::Output, &mut <Self as Index<Idx>>::Output),
+    {
+        assert_unique(indices, "for_each_pair_mut");
+
+        let ptr: *mut Self = self;
+        for (i, &a) in indices.iter().enumerate() {
+            for &b in &indices[i + 1..] {
+                // Safety: a and b are different indices, and both references
+                // are dropped before the next pair is borrowed.
+                unsafe { op(ptr.as_mut().unwrap().index_mut(a), ptr.as_mut().unwrap().index_mut(b)) }
+            }
+        }
+    }
+
+    /// Write each value to the element at the matching index, and return
+    /// the elements that were replaced in the same order as the indices.
+    /// 
+    /// The indices are checked the same way as
+    /// [`select_indices_mut`](SelectIndicesMut::select_indices_mut). This method
+    /// also panics if the number of values does not match the number of indices.
+    fn replace_indices<Idx, Values>(&'a mut self, indices: &'a [Idx], values: Values) -> Vec<<Self as Index<Idx>>::Output>
+    where
+        Self: OneToOne<Idx>,
+        <Self as Index<Idx>>::Output: Sized,
+        Idx: Sized + Eq + Hash + Copy,
+        Values: IntoIterator<Item = <Self as Index<Idx>>::Output>,
+        Values::IntoIter: ExactSizeIterator,
+    {
+        let values = values.into_iter();
+        assert!(
+            values.len() == indices.len(),
+            "replace_indices was passed {} values for {} indices!", values.len(), indices.len(),
+        );
+
+        self.select_indices_mut(indices)
+            .zip(values)
+            .map(|(elem, value)| mem::replace(elem, value))
+            .collect()
+    }
+
     /// Iterate through a collection given an iterator that produces indices.
     /// 
     /// This method requires that the collection given implements [`OneToOne`]. For the
@@ -145,8 +607,10 @@ pub trait SelectIndicesMut<'a>
     /// references to ensure that XOR mutability is not violated. If your indices are
     /// contained within a slice, consider using
     /// [`select_indices_mut`](SelectIndicesMut::select_indices_mut)
-    /// for a faster and more efficient iterator.
-    fn select_with_iter_mut<Indices>(&'a mut self, indices: Indices) -> SeqSelectIndicesMutIter<Self, Indices::IntoIter, Unindexed>
+    /// for a faster and more efficient iterator. If your iterator can never repeat
+    /// an index, such as a range, consider using
+    /// [`select_unique_iter_mut`](SelectIndicesMut::select_unique_iter_mut).
+    fn select_with_iter_mut<Indices>(&'a mut self, indices: Indices) -> SeqSelectIndicesMutIter<'a, Self, Indices::IntoIter, Unindexed>
     where
         Indices: IntoIterator,
         Indices::Item: Copy + Hash + Eq,
@@ -162,6 +626,69 @@ pub trait SelectIndicesMut<'a>
             _phantom: Default::default(),
         }
     }
+    /// Iterate through a collection given an iterator that can never produce the same
+    /// index twice, such as a range, the iterator of a set, or a [`SortedDedup`](crate::SortedDedup).
+    /// 
+    /// # Performance
+    /// 
+    /// Unlike [`select_with_iter_mut`](SelectIndicesMut::select_with_iter_mut), the
+    /// indices are never checked, because [`UniqueIndexIter`] already guarantees that
+    /// they are unique. The resulting iterator is as fast as the unchecked form.
+    /// `select_with_iter_mut` still checks a `UniqueIndexIter`, so unique sources
+    /// must be passed to this method to skip the checks.
+    /// 
+    /// ```rust
+    /// use select_indices::prelude::*;
+    /// use std::collections::{ BTreeSet, HashSet };
+    /// 
+    /// let mut data = [0; 8];
+    /// let set = BTreeSet::from([6, 1, 3]);
+    /// let hash_set = HashSet::from([7, 0]);
+    /// 
+    /// data.select_unique_iter_mut((0..8).step_by(4)).for_each(|x| *x += 1);
+    /// data.select_unique_iter_mut(set.iter().copied()).for_each(|x| *x += 2);
+    /// data.select_unique_iter_mut(hash_set.set_indices()).for_each(|x| *x += 4);
+    /// 
+    /// assert_eq!(data, [5, 2, 0, 2, 1, 0, 2, 4]);
+    /// ```
+    fn select_unique_iter_mut<Indices>(&'a mut self, indices: Indices) -> SeqSelectIndicesUncheckedMutIter<'a, Self, Indices::IntoIter, Unindexed>
+    where
+        Indices: IntoIterator,
+        Indices::IntoIter: UniqueIndexIter,
+        Indices::Item: Copy,
+        Self: OneToOne<Indices::Item>,
+    {
+        // Safety: UniqueIndexIter guarantees that no index is produced twice.
+        unsafe { self.select_with_iter_mut_unchecked(indices) }
+    }
+    /// Iterate through a collection with a set of indices, such as a
+    /// [`BTreeSet`](std::collections::BTreeSet) or [`HashSet`], in the set's own order.
+    /// 
+    /// # Performance
+    /// 
+    /// A set already holds each index once, so unlike
+    /// [`select_indices_mut`](SelectIndicesMut::select_indices_mut), the indices are
+    /// never copied or checked. See [`IndexSet`] for the supported set types.
+    /// 
+    /// ```rust
+    /// use select_indices::prelude::*;
+    /// use std::collections::BTreeSet;
+    /// 
+    /// let mut data = [1, 2, 3, 4, 5];
+    /// let set = BTreeSet::from([4, 0, 2]);
+    /// 
+    /// let selected: Vec<i32> = data.select_set_mut(&set).map(|x| { *x *= 10; *x }).collect();
+    /// 
+    /// assert_eq!(selected, [10, 30, 50]);
+    /// assert_eq!(data, [10, 2, 30, 4, 50]);
+    /// ```
+    fn select_set_mut<S>(&'a mut self, set: &'a S) -> SeqSelectIndicesUncheckedMutIter<'a, Self, S::Indices, Unindexed>
+    where
+        S: ?Sized + IndexSet<'a>,
+        Self: OneToOne<S::Idx>,
+    {
+        self.select_unique_iter_mut(set.set_indices())
+    }
 }
 
 impl<D> SelectIndicesMut<'_> for D
@@ -172,13 +699,27 @@ where
 #[cfg(feature = "rayon")]
 mod parallel {
     use std::{
-        ops::IndexMut,
+        ops::{ Index, IndexMut },
         hash::Hash,
-        collections::HashSet,
+        cmp::Ordering,
+        mem,
     };
     use crate::{
         indexed_type::Unindexed,
-        mutable::iter::{ ParSelectIndicesMutIter, ParSelectIndicesUncheckedMutIter },
+        mutable::{
+            iter::{ ParSelectIndicesMutIter, ParSelectIndicesUncheckedMutIter },
+            traits::SelectIndicesMut,
+            neighbors::ParSelectNeighborsMutIter,
+            pairs::ParPairRoundsMut,
+            csr::{ ParSelectCsrMutIter, assert_csr_frontier },
+            family::{ ParSelectFamiliesMutIter, assert_disjoint_families },
+            read_write::{ ParSelectReadWriteIter, assert_read_write },
+            groups::ParSelectGroupsMutIter,
+            labels::{ ParSelectByLabelsMut, ElementCount },
+            unique::{ UniqueIndexIter, ParIndexSet },
+            rayon::pairs::par_for_each_pair_mut,
+            validate::{ assert_unique, unique_set, assert_disjoint_groups },
+        },
     };
 
     use super::OneToOne;
@@ -187,7 +728,9 @@ mod parallel {
         prelude::*,
         slice::Iter,
         iter::Copied,
+        vec::IntoIter,
     };
+    use force_send_sync::Sync as ForceSync;
     
     /// Selectively iterate through a mutable collection
     /// with a list of indices or an index iterator.
@@ -208,7 +751,7 @@ mod parallel {
         /// violate [`OneToOne`] guarantees with the given collection.
         /// Otherwise, undefined behavior will occur and XOR mutability
         /// will be violated.
-        unsafe fn par_select_with_iter_mut_unchecked<Indices>(&'a mut self, indices: Indices) -> ParSelectIndicesUncheckedMutIter<Self, Indices::Iter, Unindexed>
+        unsafe fn par_select_with_iter_mut_unchecked<Indices>(&'a mut self, indices: Indices) -> ParSelectIndicesUncheckedMutIter<'a, Self, Indices::Iter, Unindexed>
         where
             Indices: IntoParallelIterator,
             Indices::Item: Copy,
@@ -237,7 +780,7 @@ mod parallel {
         /// violate [`OneToOne`] guarantees with the given collection.
         /// Otherwise, undefined behavior will occur and XOR mutability
         /// will be violated.
-        unsafe fn par_select_indices_mut_unchecked<Idx>(&'a mut self, indices: &'a [Idx]) -> ParSelectIndicesUncheckedMutIter<Self, Copied<Iter<'a, Idx>>, Unindexed>
+        unsafe fn par_select_indices_mut_unchecked<Idx>(&'a mut self, indices: &'a [Idx]) -> ParSelectIndicesUncheckedMutIter<'a, Self, Copied<Iter<'a, Idx>>, Unindexed>
         where
             Self: OneToOne<Idx>,
             Idx: Copy + Sync + Send,
@@ -260,18 +803,12 @@ mod parallel {
         /// not need to sync to a mutex to check for duplicate indices. Overall,
         /// this method is much faster than [`par_select_with_iter_mut`](ParSelectIndicesMut::par_select_with_iter_mut),
         /// and should be used whenever possible.
-        fn par_select_indices_mut<Idx>(&'a mut self, indices: &'a [Idx]) -> ParSelectIndicesUncheckedMutIter<Self, Copied<Iter<'a, Idx>>, Unindexed>
+        fn par_select_indices_mut<Idx>(&'a mut self, indices: &'a [Idx]) -> ParSelectIndicesUncheckedMutIter<'a, Self, Copied<Iter<'a, Idx>>, Unindexed>
         where
             Self: OneToOne<Idx>,
             Idx: Copy + Hash + Eq + Sync + Send,
         {
-            {
-                let index_check: HashSet<Idx> = indices.iter().copied().collect();
-                assert!(
-                    index_check.len() == indices.len(),
-                    "par_select_indices_mut was passed duplicate indices!",
-                )
-            }
+            assert_unique(indices, "par_select_indices_mut");
             
 
             // Safety: We just checked that all indices are unique. As long as OneToOne has
@@ -280,6 +817,342 @@ mod parallel {
             unsafe { self.par_select_with_iter_mut_unchecked(indices.into_par_iter().copied()) }
         }
 
+        /// Iterate through a collection with an owned list of indices.
+        /// 
+        /// Parallel form of [`select_owned_indices_mut`](crate::mutable::traits::SelectIndicesMut::select_owned_indices_mut).
+        fn par_select_owned_indices_mut<Idx>(&'a mut self, indices: Vec<Idx>) -> ParSelectIndicesUncheckedMutIter<'a, Self, IntoIter<Idx>, Unindexed>
+        where
+            Self: OneToOne<Idx>,
+            Idx: Copy + Hash + Eq + Sync + Send,
+        {
+            assert_unique(&indices, "par_select_owned_indices_mut");
+
+            // Safety: See par_select_indices_mut.
+            unsafe { self.par_select_with_iter_mut_unchecked(indices) }
+        }
+
+        /// Sort the elements at a slice of indices among themselves.
+        /// 
+        /// Parallel form of [`sort_selected`](crate::mutable::traits::SelectIndicesMut::sort_selected).
+        /// See [`SelectionMut::par_sort`](crate::SelectionMut::par_sort).
+        fn par_sort_selected<Idx>(&'a mut self, indices: &'a [Idx])
+        where
+            Self: OneToOne<Idx> + Sync,
+            <Self as Index<Idx>>::Output: Sized + Ord,
+            Idx: Copy + Hash + Eq + Sync,
+        {
+            self.selection_mut(indices).par_sort()
+        }
+
+        /// Sort the elements at a slice of indices among themselves with a
+        /// comparator function.
+        /// 
+        /// Parallel form of [`sort_selected_by`](crate::mutable::traits::SelectIndicesMut::sort_selected_by).
+        fn par_sort_selected_by<Idx, F>(&'a mut self, indices: &'a [Idx], compare: F)
+        where
+            Self: OneToOne<Idx> + Sync,
+            <Self as Index<Idx>>::Output: Sized,
+            Idx: Copy + Hash + Eq + Sync,
+            F: Fn(&<Self as Index<Idx>>::Output, &<Self as Index<Idx>>::Output) -> Ordering + Sync,
+        {
+            self.selection_mut(indices).par_sort_by(compare)
+        }
+
+        /// Sort the elements at a slice of indices among themselves with a key
+        /// extraction function.
+        /// 
+        /// Parallel form of [`sort_selected_by_key`](crate::mutable::traits::SelectIndicesMut::sort_selected_by_key).
+        fn par_sort_selected_by_key<Idx, K, F>(&'a mut self, indices: &'a [Idx], f: F)
+        where
+            Self: OneToOne<Idx> + Sync,
+            <Self as Index<Idx>>::Output: Sized,
+            Idx: Copy + Hash + Eq + Sync,
+            F: Fn(&<Self as Index<Idx>>::Output) -> K + Sync,
+            K: Ord,
+        {
+            self.selection_mut(indices).par_sort_by_key(f)
+        }
+
+        /// Swap the element at each index in `a_indices` with the element at the
+        /// matching index in `b_indices`.
+        /// 
+        /// Parallel form of [`swap_selections`](crate::mutable::traits::SelectIndicesMut::swap_selections).
+        fn par_swap_selections<Idx>(&'a mut self, a_indices: &'a [Idx], b_indices: &'a [Idx])
+        where
+            Self: OneToOne<Idx> + Sized + Send,
+            <Self as Index<Idx>>::Output: Sized + Send,
+            Idx: Copy + Hash + Eq + Sync + Send,
+        {
+            assert!(
+                a_indices.len() == b_indices.len(),
+                "par_swap_selections was passed {} and {} indices!", a_indices.len(), b_indices.len(),
+            );
+            {
+                let indices: Vec<Idx> = a_indices.iter().chain(b_indices).copied().collect();
+                assert_unique(&indices, "par_swap_selections");
+            }
+
+            let ptr: ForceSync<*mut Self> = unsafe { ForceSync::new(self) };
+            a_indices.par_iter().zip(b_indices).for_each(|(&a, &b)| {
+                // Safety: We just checked that every index is unique, so no two
+                // pairs, and no two elements of one pair, are the same element.
+                // Both element pointers come from one reborrow of the collection.
+                unsafe {
+                    let data = (*ptr).as_mut().unwrap();
+                    let a: *mut <Self as Index<Idx>>::Output = data.index_mut(a);
+                    let b: *mut <Self as Index<Idx>>::Output = data.index_mut(b);
+                    std::ptr::swap(a, b);
+                }
+            });
+        }
+
+        /// Swap the element at each index in `indices` with the element at the
+        /// matching index of another collection.
+        /// 
+        /// Parallel form of [`swap_between`](crate::mutable::traits::SelectIndicesMut::swap_between).
+        fn par_swap_between<Idx, Other, OtherIdx>(&'a mut self, indices: &'a [Idx], other: &'a mut Other, other_indices: &'a [OtherIdx])
+        where
+            Self: OneToOne<Idx> + Sized + Send,
+            <Self as Index<Idx>>::Output: Sized + Send,
+            Idx: Copy + Hash + Eq + Sync + Send,
+            Other: OneToOne<OtherIdx, Output = <Self as Index<Idx>>::Output> + Send,
+            OtherIdx: Copy + Hash + Eq + Sync + Send,
+        {
+            assert!(
+                indices.len() == other_indices.len(),
+                "par_swap_between was passed {} and {} indices!", indices.len(), other_indices.len(),
+            );
+
+            self.par_select_indices_mut(indices)
+                .zip(other.par_select_indices_mut(other_indices))
+                .for_each(|(a, b)| mem::swap(a, b));
+        }
+
+        /// Iterate through a collection with a slice of centre indices, producing each
+        /// centre mutably alongside an iterator over its neighbours.
+        /// 
+        /// Parallel form of [`select_with_neighbors_mut`](crate::mutable::traits::SelectIndicesMut::select_with_neighbors_mut).
+        /// Every centre may be mutated while any other centre's neighbours are being
+        /// read, so no centre may be a neighbour of any centre.
+        fn par_select_with_neighbors_mut<Idx, NeighborFn, N>(&'a mut self, centers: &'a [Idx], neighbor_fn: NeighborFn) -> ParSelectNeighborsMutIter<'a, Self, Copied<Iter<'a, Idx>>, Idx, NeighborFn>
+        where
+            Self: OneToOne<Idx>,
+            Idx: Copy + Hash + Eq + Sync + Send,
+            NeighborFn: Fn(Idx) -> N + Sync + Send,
+            N: IntoIterator<Item = Idx>,
+        {
+            ParSelectNeighborsMutIter {
+                center_set: unique_set(centers, "par_select_with_neighbors_mut"),
+                data: self,
+                centers: centers.into_par_iter().copied(),
+                neighbor_fn,
+                _phantom: Default::default(),
+            }
+        }
+
+        /// Iterate through the node data of a graph with a slice of frontier nodes,
+        /// producing each frontier node mutably alongside an iterator over its neighbours.
+        /// 
+        /// Parallel form of [`select_csr_mut`](crate::mutable::traits::SelectIndicesMut::select_csr_mut).
+        fn par_select_csr_mut(&'a mut self, offsets: &'a [usize], targets: &'a [usize], frontier: &'a [usize]) -> ParSelectCsrMutIter<'a, Self, Copied<Iter<'a, usize>>>
+        where
+            Self: OneToOne<usize>,
+        {
+            assert_csr_frontier(offsets, targets, frontier, "par_select_csr_mut");
+
+            ParSelectCsrMutIter {
+                data: self,
+                offsets,
+                targets,
+                frontier: frontier.into_par_iter().copied(),
+                _phantom: Default::default(),
+            }
+        }
+
+        /// Select a batch of parents, each with a slice of children, producing
+        /// each parent mutably alongside a mutable iterator over its children.
+        /// 
+        /// Batched parallel form of [`select_one_and_many_mut`](crate::mutable::traits::SelectIndicesMut::select_one_and_many_mut).
+        /// Families run in parallel, and the children of each family are visited
+        /// sequentially. Besides the checks of the sequential form, this method
+        /// panics if any index appears in more than one family.
+        /// 
+        /// ```rust
+        /// use select_indices::prelude::*;
+        /// use rayon::prelude::*;
+        /// 
+        /// let mut depths = vec![1, 0, 0, 5, 0, 0];
+        /// let families: [(usize, &[usize]); 2] = [(0, &[1, 2]), (3, &[4, 5])];
+        /// 
+        /// depths.par_select_one_and_many_mut(&families)
+        ///     .for_each(|(parent, children)| children.for_each(|child| *child = *parent + 1));
+        /// 
+        /// assert_eq!(depths, [1, 2, 2, 5, 6, 6]);
+        /// ```
+        fn par_select_one_and_many_mut<Idx>(&'a mut self, families: &'a [(Idx, &'a [Idx])]) -> ParSelectFamiliesMutIter<'a, Self, Idx>
+        where
+            Self: OneToOne<Idx>,
+            Idx: Copy + Hash + Eq + Sync + Send,
+        {
+            assert_disjoint_families(families, "par_select_one_and_many_mut");
+
+            ParSelectFamiliesMutIter {
+                data: self,
+                families,
+            }
+        }
+
+        /// Iterate through a collection with a slice of read indices and a slice of
+        /// write indices, producing each read element immutably alongside the write
+        /// element at the same position.
+        /// 
+        /// Parallel form of [`select_read_write`](crate::mutable::traits::SelectIndicesMut::select_read_write).
+        fn par_select_read_write<Idx>(&'a mut self, reads: &'a [Idx], writes: &'a [Idx]) -> ParSelectReadWriteIter<'a, Self, Copied<Iter<'a, Idx>>, Copied<Iter<'a, Idx>>>
+        where
+            Self: OneToOne<Idx>,
+            Idx: Copy + Hash + Eq + Sync + Send,
+        {
+            assert_read_write(reads, writes, "par_select_read_write");
+
+            ParSelectReadWriteIter {
+                data: self,
+                reads: reads.into_par_iter().copied(),
+                writes: writes.into_par_iter().copied(),
+                _phantom: Default::default(),
+            }
+        }
+
+        /// Iterate through a collection one group of indices at a time, producing
+        /// a mutable iterator over the elements of each group.
+        /// 
+        /// Parallel form of [`select_groups_mut`](crate::mutable::traits::SelectIndicesMut::select_groups_mut).
+        /// Groups run in parallel, and the elements of each group are visited sequentially.
+        fn par_select_groups_mut<Idx, G>(&'a mut self, groups: &'a [G]) -> ParSelectGroupsMutIter<'a, Self, Iter<'a, G>, Idx>
+        where
+            Self: OneToOne<Idx>,
+            Idx: Copy + Hash + Eq + Sync + Send,
+            G: AsRef<[Idx]> + Sync,
+        {
+            assert_disjoint_groups(groups, "par_select_groups_mut");
+
+            ParSelectGroupsMutIter {
+                data: self,
+                groups: groups.into_par_iter(),
+                _phantom: Default::default(),
+            }
+        }
+
+        /// Iterate through a collection one class at a time, where `labels[i]` is the
+        /// class of the element at index `i`, producing each label alongside a mutable
+        /// iterator over the elements of its class.
+        /// 
+        /// Every index belongs to exactly one class, so the classes are disjoint without
+        /// any checks or hashing. Labels are produced in ascending order, and the elements
+        /// of each class in ascending index order. Classes run in parallel, and the
+        /// elements of each class are visited sequentially.
+        /// 
+        /// There must be exactly one label for every element of the collection, or
+        /// this method panics. The collection reports its length through
+        /// [`ElementCount`](crate::ElementCount).
+        /// 
+        /// ```rust
+        /// use select_indices::prelude::*;
+        /// use rayon::prelude::*;
+        /// 
+        /// let mut data = vec![1, 2, 3, 4, 5];
+        /// let labels = [2, 0, 2, 0, 7];
+        /// 
+        /// data.par_select_by_labels_mut(&labels)
+        ///     .for_each(|(label, class)| class.for_each(|x| *x += 10 * label as i32));
+        /// 
+        /// assert_eq!(data, [21, 2, 23, 4, 75]);
+        /// ```
+        fn par_select_by_labels_mut(&'a mut self, labels: &[u32]) -> ParSelectByLabelsMut<'a, Self>
+        where
+            Self: OneToOne<usize> + ElementCount,
+        {
+            ParSelectByLabelsMut::new(self, labels)
+        }
+
+        /// Visit both elements of each pair in a slice of index pairs in parallel.
+        /// 
+        /// Unlike [`select_pairs_mut`](crate::mutable::traits::SelectIndicesMut::select_pairs_mut),
+        /// pairs may share indices, as the edges of a graph do. The pairs are split into
+        /// rounds in which no index appears twice, and each round runs in parallel.
+        /// This method still panics if a pair connects an index to itself.
+        /// 
+        /// Because pairs from different rounds can share an element, the result is
+        /// not a parallel iterator. It only offers a `for_each` method, which finishes
+        /// each round before the next one starts.
+        /// 
+        /// ```rust
+        /// use select_indices::prelude::*;
+        /// 
+        /// let mut nodes = vec![0, 0, 0, 0];
+        /// let edges = [(0, 1), (1, 2), (2, 3), (3, 0)];
+        /// 
+        /// nodes.par_select_pairs_mut(&edges).for_each(|(a, b)| {
+        ///     *a += 1;
+        ///     *b += 1;
+        /// });
+        /// 
+        /// assert_eq!(nodes, [2, 2, 2, 2]);
+        /// ```
+        fn par_select_pairs_mut<Idx>(&'a mut self, pairs: &[(Idx, Idx)]) -> ParPairRoundsMut<'a, Self, Idx>
+        where
+            Self: OneToOne<Idx> + Sized + Send,
+            <Self as Index<Idx>>::Output: Send,
+            Idx: Copy + Hash + Eq + Sync + Send,
+        {
+            ParPairRoundsMut::new(self, pairs)
+        }
+
+        /// Call `op` on every unordered pair of elements at a slice of indices.
+        /// 
+        /// Parallel form of [`for_each_pair_mut`](crate::mutable::traits::SelectIndicesMut::for_each_pair_mut).
+        /// 
+        /// # Performance
+        /// 
+        /// The indices are split into blocks, and pairs of blocks are scheduled in
+        /// rounds so that no two tasks in the same round share a block. Each task
+        /// visits every pair between its two blocks sequentially, so no element is
+        /// ever borrowed by two tasks at once.
+        fn par_for_each_pair_mut<Idx, Op>(&'a mut self, indices: &'a [Idx], op: Op)
+        where
+            Self: OneToOne<Idx> + Sized + Send,
+            <Self as Index<Idx>>::Output: Send,
+            Idx: Copy + Hash + Eq + Sync + Send,
+            Op: Fn(&mut <Self as Index<Idx>>::Output, &mut <Self as Index<Idx>>::Output) + Sync + Send,
+        {
+            assert_unique(indices, "par_for_each_pair_mut");
+
+            par_for_each_pair_mut(self, indices, op)
+        }
+
+        /// Write each value to the element at the matching index, and return
+        /// the elements that were replaced in the same order as the indices.
+        /// 
+        /// Parallel form of [`replace_indices`](crate::mutable::traits::SelectIndicesMut::replace_indices).
+        fn par_replace_indices<Idx, Values>(&'a mut self, indices: &'a [Idx], values: Values) -> Vec<<Self as Index<Idx>>::Output>
+        where
+            Self: OneToOne<Idx> + Sized + Send,
+            <Self as Index<Idx>>::Output: Sized + Send,
+            Idx: Copy + Hash + Eq + Sync + Send,
+            Values: IntoParallelIterator<Item = <Self as Index<Idx>>::Output>,
+            Values::Iter: IndexedParallelIterator,
+        {
+            let values = values.into_par_iter();
+            assert!(
+                values.len() == indices.len(),
+                "par_replace_indices was passed {} values for {} indices!", values.len(), indices.len(),
+            );
+
+            self.par_select_indices_mut(indices)
+                .zip(values)
+                .map(|(elem, value)| mem::replace(elem, value))
+                .collect()
+        }
+
         /// Iterate through a collection given an iterator that produces indices.
         /// 
         /// This method requires that the collection given implements [`OneToOne`]. For the
@@ -288,12 +1161,14 @@ mod parallel {
         /// 
         /// # Performance
         /// 
-        /// The iterator produced by this method maintains a [`Mutex`](std::sync::Mutex)-held [`HashSet`]
+        /// The iterator produced by this method maintains a [`Mutex`](std::sync::Mutex)-held [`HashSet`](std::collections::HashSet)
         /// of previously returned references to ensure that XOR mutability is
         /// not violated. This massively drains performance, so you might instead want to
         /// collect your indices into a [`Vec`], then use
         /// [`par_select_indices_mut`](ParSelectIndicesMut::par_select_indices_mut).
-        fn par_select_with_iter_mut<Indices>(&'a mut self, indices: Indices) -> ParSelectIndicesMutIter<Self, Indices::Iter, Unindexed>
+        /// If your iterator can never repeat an index, such as a range, consider using
+        /// [`par_select_unique_iter_mut`](ParSelectIndicesMut::par_select_unique_iter_mut).
+        fn par_select_with_iter_mut<Indices>(&'a mut self, indices: Indices) -> ParSelectIndicesMutIter<'a, Self, Indices::Iter, Unindexed>
         where
             Indices: IntoParallelIterator,
             Indices::Item: Copy,
@@ -306,6 +1181,36 @@ mod parallel {
                 _phantom: Default::default(),
             }
         }
+
+        /// Iterate through a collection given a parallel iterator that can never produce
+        /// the same index twice.
+        /// 
+        /// Parallel form of [`select_unique_iter_mut`](crate::mutable::traits::SelectIndicesMut::select_unique_iter_mut).
+        /// Unlike [`par_select_with_iter_mut`](ParSelectIndicesMut::par_select_with_iter_mut),
+        /// this does not need a shared set of previously returned references.
+        fn par_select_unique_iter_mut<Indices>(&'a mut self, indices: Indices) -> ParSelectIndicesUncheckedMutIter<'a, Self, Indices::Iter, Unindexed>
+        where
+            Indices: IntoParallelIterator,
+            Indices::Iter: UniqueIndexIter,
+            Indices::Item: Copy,
+            Self: OneToOne<Indices::Item>,
+        {
+            // Safety: UniqueIndexIter guarantees that no index is produced twice.
+            unsafe { self.par_select_with_iter_mut_unchecked(indices) }
+        }
+
+        /// Iterate through a collection with a set of indices, such as a
+        /// [`BTreeSet`](std::collections::BTreeSet) or [`HashSet`](std::collections::HashSet).
+        /// 
+        /// Parallel form of [`select_set_mut`](crate::mutable::traits::SelectIndicesMut::select_set_mut).
+        /// See [`ParIndexSet`] for the supported set types.
+        fn par_select_set_mut<S>(&'a mut self, set: &'a S) -> ParSelectIndicesUncheckedMutIter<'a, Self, S::Indices, Unindexed>
+        where
+            S: ?Sized + ParIndexSet<'a>,
+            Self: OneToOne<S::Idx>,
+        {
+            self.par_select_unique_iter_mut(set.par_set_indices())
+        }
     }
 
     impl<D> ParSelectIndicesMut<'_> for D
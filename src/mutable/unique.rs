@@ -0,0 +1,325 @@
+use std::{
+    ops::{ Range, RangeInclusive },
+    iter::{ Copied, Cloned, Rev, Skip, Take, StepBy, Filter, Fuse, Peekable, SkipWhile, TakeWhile },
+    collections::{ btree_set, hash_set, btree_map, BTreeSet, HashSet, hash_map::RandomState },
+    cmp::Ordering,
+};
+use crate::IndexPattern;
+
+/// An iterator, or a parallel iterator, that never produces the same index twice.
+///
+/// Selecting with a `UniqueIndexIter`, through
+/// [`select_unique_iter_mut`](crate::SelectIndicesMut::select_unique_iter_mut),
+/// skips the duplicate tracking of
+/// [`select_with_iter_mut`](crate::SelectIndicesMut::select_with_iter_mut).
+/// `select_with_iter_mut` itself always tracks duplicates, even for a
+/// `UniqueIndexIter`, because choosing a different iterator for some index
+/// sources would need specialization.
+///
+/// This is implemented for ranges, for the iterators of B-tree sets and the keys
+/// of B-tree maps of primitive integers, for [`SortedDedup`], and for adapters
+/// that only produce some of the items of a `UniqueIndexIter`, such as [`Filter`]
+/// or [`StepBy`]. Sets of other types are not included, because a set whose
+/// element type has an inconsistent [`Eq`] or [`Ord`] can contain equal elements.
+/// Hash sets and hash maps are not included either, because their hasher can be
+/// any [`BuildHasher`](std::hash::BuildHasher), including one that hashes the
+/// same index differently. For a [`HashSet`] with the default hasher,
+/// [`set_indices`](IndexSet::set_indices) returns a `UniqueIndexIter` in place
+/// of [`HashSet::iter`]. Select from other hash sets and hash maps with
+/// [`select_with_iter_mut`](crate::SelectIndicesMut::select_with_iter_mut).
+///
+/// # Safety
+/// No two items produced by the iterator may be equal. For iterators over
+/// references, such as [`btree_set::Iter`], this applies to the values behind
+/// the references.
+pub unsafe trait UniqueIndexIter {}
+
+unsafe impl<A> UniqueIndexIter for Range<A> where Range<A>: Iterator {}
+unsafe impl<A> UniqueIndexIter for RangeInclusive<A> where RangeInclusive<A>: Iterator {}
+
+unsafe impl<I: UniqueIndexIter> UniqueIndexIter for Copied<I> {}
+unsafe impl<I: UniqueIndexIter> UniqueIndexIter for Cloned<I> {}
+unsafe impl<I: UniqueIndexIter> UniqueIndexIter for Rev<I> {}
+unsafe impl<I: UniqueIndexIter> UniqueIndexIter for Skip<I> {}
+unsafe impl<I: UniqueIndexIter> UniqueIndexIter for Take<I> {}
+unsafe impl<I: UniqueIndexIter> UniqueIndexIter for StepBy<I> {}
+unsafe impl<I: UniqueIndexIter> UniqueIndexIter for Fuse<I> {}
+unsafe impl<I: UniqueIndexIter + Iterator> UniqueIndexIter for Peekable<I> {}
+unsafe impl<I: UniqueIndexIter, P> UniqueIndexIter for Filter<I, P> {}
+unsafe impl<I: UniqueIndexIter, P> UniqueIndexIter for SkipWhile<I, P> {}
+unsafe impl<I: UniqueIndexIter, P> UniqueIndexIter for TakeWhile<I, P> {}
+
+pub(crate) mod primitive {
+    /// The primitive integers, whose [`Eq`], [`Hash`](std::hash::Hash) and [`Ord`]
+    /// are known to agree, so that sets of them never contain equal elements.
+    pub trait PrimitiveIndex: Copy + Ord + std::hash::Hash {}
+
+    macro_rules! impl_primitive_index {
+        ($($int:ty),+) => {
+            $(impl PrimitiveIndex for $int {})+
+        };
+    }
+
+    impl_primitive_index!(usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128);
+}
+use self::primitive::PrimitiveIndex;
+
+unsafe impl<T: PrimitiveIndex> UniqueIndexIter for btree_set::Iter<'_, T> {}
+unsafe impl<T: PrimitiveIndex> UniqueIndexIter for btree_set::IntoIter<T> {}
+unsafe impl<T: PrimitiveIndex> UniqueIndexIter for btree_set::Range<'_, T> {}
+unsafe impl<K: PrimitiveIndex, V> UniqueIndexIter for btree_map::Keys<'_, K, V> {}
+unsafe impl<K: PrimitiveIndex, V> UniqueIndexIter for btree_map::IntoKeys<K, V> {}
+unsafe impl<I> UniqueIndexIter for SortedDedup<I> where I: Iterator, I::Item: PrimitiveIndex {}
+
+/// A set of indices that can be selected from directly, with
+/// [`select_set_mut`](crate::SelectIndicesMut::select_set_mut).
+///
+/// This is implemented for [`BTreeSet`] and [`HashSet`] of primitive integers,
+/// and for [`IndexPattern`]. The indices are produced in the set's own iteration
+/// order, and are never checked, because the set already holds each index once.
+///
+/// Only hash sets with the default [`RandomState`] hasher are included. A set
+/// with a custom [`BuildHasher`](std::hash::BuildHasher) that hashes the same
+/// index differently can hold it twice, so select from those with
+/// [`select_with_iter_mut`](crate::SelectIndicesMut::select_with_iter_mut) instead.
+pub trait IndexSet<'s>
+{
+    /// The type of the indices in the set.
+    type Idx: Copy;
+    /// The iterator over the indices in the set.
+    type Indices: Iterator<Item = Self::Idx> + UniqueIndexIter;
+
+    /// Iterates through the indices in the set.
+    fn set_indices(&'s self) -> Self::Indices;
+}
+
+impl<'s, T: PrimitiveIndex + 's> IndexSet<'s> for BTreeSet<T>
+{
+    type Idx = T;
+    type Indices = Copied<btree_set::Iter<'s, T>>;
+
+    fn set_indices(&'s self) -> Self::Indices {
+        self.iter().copied()
+    }
+}
+
+impl<'s, T: PrimitiveIndex + 's> IndexSet<'s> for HashSet<T, RandomState>
+{
+    type Idx = T;
+    type Indices = HashSetIndices<'s, T>;
+
+    fn set_indices(&'s self) -> Self::Indices {
+        HashSetIndices {
+            iter: self.iter(),
+        }
+    }
+}
+
+/// Iterates through the indices of a [`HashSet`] with the default hasher.
+///
+/// Return type for [`IndexSet::set_indices`] on [`HashSet`].
+pub struct HashSetIndices<'s, T>
+{
+    pub(crate) iter: hash_set::Iter<'s, T>,
+}
+
+impl<T: Copy> Iterator for HashSetIndices<'_, T>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().copied()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T: Copy> ExactSizeIterator for HashSetIndices<'_, T> {}
+
+// Safety: HashSetIndices is only created from a HashSet<T, RandomState>, which
+// hashes equal primitive integers the same way, so it never holds an index twice.
+unsafe impl<T: PrimitiveIndex> UniqueIndexIter for HashSetIndices<'_, T> {}
+
+impl<'s> IndexSet<'s> for IndexPattern
+{
+    type Idx = usize;
+    type Indices = crate::pattern::Iter<'s>;
+
+    fn set_indices(&'s self) -> Self::Indices {
+        self.iter()
+    }
+}
+
+/// Skips repeated indices of an iterator that produces indices in ascending order.
+///
+/// This panics if an index is smaller than the index before it, because an
+/// unsorted iterator could repeat an index that was already skipped past.
+///
+/// ```rust
+/// use select_indices::prelude::*;
+///
+/// let mut data = [0; 6];
+///
+/// data.select_unique_iter_mut(SortedDedup::new(vec![1, 1, 2, 4, 4, 4]))
+///     .for_each(|x| *x += 1);
+///
+/// assert_eq!(data, [0, 1, 1, 0, 1, 0]);
+/// ```
+pub struct SortedDedup<I>
+where
+    I: Iterator,
+{
+    pub(crate) iter: I,
+    pub(crate) last: Option<I::Item>,
+}
+
+impl<I> SortedDedup<I>
+where
+    I: Iterator,
+{
+    /// Wraps an iterator of ascending indices.
+    pub fn new<Indices>(indices: Indices) -> Self
+    where
+        Indices: IntoIterator<IntoIter = I>,
+    {
+        SortedDedup {
+            iter: indices.into_iter(),
+            last: None,
+        }
+    }
+}
+
+impl<I> Iterator for SortedDedup<I>
+where
+    I: Iterator,
+    I::Item: Ord + Copy,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let index = self.iter.next()?;
+            if let Some(last) = self.last {
+                match index.cmp(&last) {
+                    Ordering::Equal => continue,
+                    Ordering::Less => panic!("SortedDedup was passed unsorted indices!"),
+                    Ordering::Greater => {},
+                }
+            }
+            self.last = Some(index);
+            return Some(index);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower.min(1), upper)
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod parallel {
+    use std::collections::{ BTreeSet, HashSet, hash_map::RandomState };
+    use super::{ UniqueIndexIter, PrimitiveIndex };
+    use crate::IndexPattern;
+    use ::rayon::{
+        prelude::*,
+        range,
+        range_inclusive,
+        iter::{ Copied, Cloned, Rev, Skip, Take, StepBy, Filter },
+        iter::plumbing::UnindexedConsumer,
+        collections::{ btree_set, hash_set },
+    };
+
+    unsafe impl<A> UniqueIndexIter for range::Iter<A> where A: Send, range::Iter<A>: ::rayon::iter::ParallelIterator {}
+    unsafe impl<A> UniqueIndexIter for range_inclusive::Iter<A> where A: Send, range_inclusive::Iter<A>: ::rayon::iter::ParallelIterator {}
+
+    // Older rayon versions bound these adapters on their iterator, so the
+    // impls repeat those bounds.
+    unsafe impl<I: UniqueIndexIter + ParallelIterator> UniqueIndexIter for Copied<I> {}
+    unsafe impl<I: UniqueIndexIter + ParallelIterator> UniqueIndexIter for Cloned<I> {}
+    unsafe impl<I: UniqueIndexIter + IndexedParallelIterator> UniqueIndexIter for Rev<I> {}
+    unsafe impl<I: UniqueIndexIter + IndexedParallelIterator> UniqueIndexIter for Skip<I> {}
+    unsafe impl<I: UniqueIndexIter + IndexedParallelIterator> UniqueIndexIter for Take<I> {}
+    unsafe impl<I: UniqueIndexIter + IndexedParallelIterator> UniqueIndexIter for StepBy<I> {}
+    unsafe impl<I: UniqueIndexIter + ParallelIterator, P> UniqueIndexIter for Filter<I, P> {}
+
+    unsafe impl<T: PrimitiveIndex + Sync> UniqueIndexIter for btree_set::Iter<'_, T> {}
+    unsafe impl<T: PrimitiveIndex + Send> UniqueIndexIter for btree_set::IntoIter<T> {}
+
+    /// A set of indices that can be selected from in parallel, with
+    /// [`par_select_set_mut`](crate::ParSelectIndicesMut::par_select_set_mut).
+    ///
+    /// Parallel form of [`IndexSet`](super::IndexSet).
+    pub trait ParIndexSet<'s>
+    {
+        /// The type of the indices in the set.
+        type Idx: Copy + Send;
+        /// The parallel iterator over the indices in the set.
+        type Indices: ParallelIterator<Item = Self::Idx> + UniqueIndexIter;
+
+        /// Iterates in parallel through the indices in the set.
+        fn par_set_indices(&'s self) -> Self::Indices;
+    }
+
+    impl<'s, T: PrimitiveIndex + Send + Sync + 's> ParIndexSet<'s> for BTreeSet<T>
+    {
+        type Idx = T;
+        type Indices = Copied<btree_set::Iter<'s, T>>;
+
+        fn par_set_indices(&'s self) -> Self::Indices {
+            self.par_iter().copied()
+        }
+    }
+
+    impl<'s, T: PrimitiveIndex + Send + Sync + 's> ParIndexSet<'s> for HashSet<T, RandomState>
+    {
+        type Idx = T;
+        type Indices = ParHashSetIndices<'s, T>;
+
+        fn par_set_indices(&'s self) -> Self::Indices {
+            ParHashSetIndices {
+                iter: self.par_iter(),
+            }
+        }
+    }
+
+    /// Iterates in parallel through the indices of a [`HashSet`] with the default hasher.
+    ///
+    /// Return type for [`ParIndexSet::par_set_indices`] on [`HashSet`].
+    pub struct ParHashSetIndices<'s, T>
+    where
+        T: PrimitiveIndex + Sync,
+    {
+        pub(crate) iter: hash_set::Iter<'s, T>,
+    }
+
+    impl<T: PrimitiveIndex + Send + Sync> ParallelIterator for ParHashSetIndices<'_, T>
+    {
+        type Item = T;
+
+        fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where
+            C: UnindexedConsumer<Self::Item>
+        {
+            self.iter.copied().drive_unindexed(consumer)
+        }
+    }
+
+    // Safety: Same as HashSetIndices.
+    unsafe impl<T: PrimitiveIndex + Sync> UniqueIndexIter for ParHashSetIndices<'_, T> {}
+
+    impl<'s> ParIndexSet<'s> for IndexPattern
+    {
+        type Idx = usize;
+        type Indices = crate::pattern::ParIter<'s>;
+
+        fn par_set_indices(&'s self) -> Self::Indices {
+            self.into_par_iter()
+        }
+    }
+}
+#[cfg(feature = "rayon")]
+pub use self::parallel::ParIndexSet;
\ No newline at end of file
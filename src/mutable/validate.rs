@@ -0,0 +1,55 @@
+use std::{
+    hash::Hash,
+    collections::HashSet,
+};
+
+/// Panics if `indices` contains the same index more than once.
+pub(crate) fn assert_unique<Idx>(indices: &[Idx], caller: &str)
+where
+    Idx: Eq + Hash + Copy,
+{
+    unique_set(indices, caller);
+}
+
+/// Collects `indices` into a [`HashSet`], and panics if it contains the same
+/// index more than once.
+pub(crate) fn unique_set<Idx>(indices: &[Idx], caller: &str) -> HashSet<Idx>
+where
+    Idx: Eq + Hash + Copy,
+{
+    let index_check: HashSet<Idx> = indices.iter().copied().collect();
+    assert!(
+        index_check.len() == indices.len(),
+        "{} was passed duplicate indices!", caller,
+    );
+    index_check
+}
+
+/// Panics if any index in `indices` is not less than `len`.
+pub(crate) fn assert_in_bounds(indices: &[usize], len: usize, caller: &str)
+{
+    for &index in indices {
+        assert!(
+            index < len,
+            "{} was passed an out of bounds index: the len is {} but the index is {}", caller, len, index,
+        )
+    }
+}
+
+/// Returns the address of an element, so that two indices that produce
+/// the same element can be detected.
+pub(crate) fn element_address<T: ?Sized>(element: &T) -> usize
+{
+    (element as *const T).cast::<()>() as usize
+}
+
+/// Panics if any index appears more than once across all groups, whether in
+/// the same group or in different groups.
+pub(crate) fn assert_disjoint_groups<Idx, G>(groups: &[G], caller: &str)
+where
+    Idx: Eq + Hash + Copy,
+    G: AsRef<[Idx]>,
+{
+    let indices: Vec<Idx> = groups.iter().flat_map(|group| group.as_ref().iter().copied()).collect();
+    assert_unique(&indices, caller);
+}
\ No newline at end of file
@@ -0,0 +1,196 @@
+use std::ptr;
+use crate::mutable::validate::{ assert_unique, assert_in_bounds };
+
+/// Marks an element that was not selected by any index.
+const UNSELECTED: usize = usize::MAX;
+
+/// Removes or inserts multiple elements of a [`Vec`] with a list of indices.
+///
+/// Like [`select_indices_mut`](crate::SelectIndicesMut::select_indices_mut),
+/// every method panics if the indices contain a duplicate or an out of bounds
+/// index. These checks happen before the [`Vec`] is modified.
+pub trait SelectIndicesVec<T>
+{
+    /// Removes the elements at the given indices and returns them in
+    /// the same order as the indices. The remaining elements keep their
+    /// order.
+    ///
+    /// # Performance
+    ///
+    /// This method runs in O(n) time and moves every remaining element
+    /// at most once.
+    fn extract_indices(&mut self, indices: &[usize]) -> Vec<T>;
+
+    /// Removes the elements at the given indices and returns them in
+    /// the same order as the indices. Each removed element is replaced
+    /// by the last element of the [`Vec`], as in [`Vec::swap_remove`],
+    /// so the remaining elements do not keep their order.
+    ///
+    /// # Performance
+    ///
+    /// This method runs in O(k log k) time, where k is the number of indices.
+    fn swap_remove_indices(&mut self, indices: &[usize]) -> Vec<T>;
+
+    /// Removes and drops the elements at the given indices. The
+    /// remaining elements keep their order.
+    ///
+    /// # Performance
+    ///
+    /// This method runs in O(n) time and moves every remaining element
+    /// at most once.
+    fn remove_indices(&mut self, indices: &[usize]);
+
+    /// Keeps only the elements at the given indices, in their original
+    /// order, and drops the rest.
+    ///
+    /// # Performance
+    ///
+    /// This method runs in O(n) time and moves every remaining element
+    /// at most once.
+    fn retain_indices(&mut self, indices: &[usize]);
+
+    /// Inserts every `(index, value)` pair so that each value ends up at
+    /// its index in the resulting [`Vec`]. The existing elements keep their
+    /// order and fill the remaining positions.
+    ///
+    /// Every index must be less than the length of the resulting [`Vec`].
+    ///
+    /// # Performance
+    ///
+    /// This method runs in O(n + k log k) time, where k is the number of
+    /// values, and moves every existing element at most once.
+    fn insert_at_indices(&mut self, values: Vec<(usize, T)>);
+}
+
+impl<T> SelectIndicesVec<T> for Vec<T>
+{
+    fn extract_indices(&mut self, indices: &[usize]) -> Vec<T>
+    {
+        assert_in_bounds(indices, self.len(), "extract_indices");
+        assert_unique(indices, "extract_indices");
+
+        let slots = selection_slots(self.len(), indices);
+        let mut extracted: Vec<Option<T>> = Vec::with_capacity(indices.len());
+        extracted.resize_with(indices.len(), || None);
+
+        compact(self, |i| slots[i] == UNSELECTED, |i, item| extracted[slots[i]] = Some(item));
+
+        extracted.into_iter().map(Option::unwrap).collect()
+    }
+
+    fn swap_remove_indices(&mut self, indices: &[usize]) -> Vec<T>
+    {
+        assert_in_bounds(indices, self.len(), "swap_remove_indices");
+        assert_unique(indices, "swap_remove_indices");
+
+        let mut order: Vec<usize> = (0..indices.len()).collect();
+        order.sort_unstable_by(|&a, &b| indices[b].cmp(&indices[a]));
+
+        // Removing from the highest index down means the last element
+        // is never one that still needs to be removed.
+        let mut removed: Vec<Option<T>> = Vec::with_capacity(indices.len());
+        removed.resize_with(indices.len(), || None);
+        for slot in order {
+            removed[slot] = Some(self.swap_remove(indices[slot]));
+        }
+
+        removed.into_iter().map(Option::unwrap).collect()
+    }
+
+    fn remove_indices(&mut self, indices: &[usize])
+    {
+        assert_in_bounds(indices, self.len(), "remove_indices");
+        assert_unique(indices, "remove_indices");
+
+        let slots = selection_slots(self.len(), indices);
+        compact(self, |i| slots[i] == UNSELECTED, |_, item| drop(item));
+    }
+
+    fn retain_indices(&mut self, indices: &[usize])
+    {
+        assert_in_bounds(indices, self.len(), "retain_indices");
+        assert_unique(indices, "retain_indices");
+
+        let slots = selection_slots(self.len(), indices);
+        compact(self, |i| slots[i] != UNSELECTED, |_, item| drop(item));
+    }
+
+    fn insert_at_indices(&mut self, mut values: Vec<(usize, T)>)
+    {
+        let old_len = self.len();
+        let new_len = old_len + values.len();
+        {
+            let indices: Vec<usize> = values.iter().map(|(index, _)| *index).collect();
+            assert_in_bounds(&indices, new_len, "insert_at_indices");
+            assert_unique(&indices, "insert_at_indices");
+        }
+
+        values.sort_unstable_by_key(|(index, _)| *index);
+        self.reserve(values.len());
+
+        // Safety: Starting from the back, every existing element is moved
+        // once to its final position, which is never before its current one,
+        // and every value is written into the gap left for it. Nothing in the
+        // loop can panic, so the length is only updated once every position
+        // holds an element.
+        unsafe {
+            let base = self.as_mut_ptr();
+            let mut src = old_len;
+            let mut dst = new_len;
+            while let Some((index, value)) = values.pop() {
+                let count = dst - index - 1;
+                src -= count;
+                dst -= count;
+                ptr::copy(base.add(src), base.add(dst), count);
+
+                dst -= 1;
+                ptr::write(base.add(dst), value);
+            }
+            self.set_len(new_len);
+        }
+    }
+}
+
+/// Maps every position in a collection of length `len` to its position
+/// in `indices`, or [`UNSELECTED`].
+fn selection_slots(len: usize, indices: &[usize]) -> Vec<usize>
+{
+    let mut slots = vec![UNSELECTED; len];
+    for (slot, &index) in indices.iter().enumerate() {
+        slots[index] = slot;
+    }
+    slots
+}
+
+/// Moves every element whose position passes `keep` towards the front of `vec`
+/// in order, and hands every other element to `removed` along with its position.
+fn compact<T, Keep, Removed>(vec: &mut Vec<T>, mut keep: Keep, mut removed: Removed)
+where
+    Keep: FnMut(usize) -> bool,
+    Removed: FnMut(usize, T),
+{
+    let len = vec.len();
+    let base = vec.as_mut_ptr();
+    let mut kept = 0;
+
+    // Safety: While elements are being moved, the length is zero, so a panic
+    // in `removed` leaks the remaining elements instead of dropping them twice.
+    // Every element is either read out exactly once or moved to a position at
+    // or before its own.
+    unsafe {
+        vec.set_len(0);
+        for i in 0..len {
+            let item = base.add(i);
+            if keep(i) {
+                if kept != i {
+                    ptr::copy_nonoverlapping(item, base.add(kept), 1);
+                }
+                kept += 1;
+            }
+            else {
+                removed(i, ptr::read(item));
+            }
+        }
+        vec.set_len(kept);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,230 @@
+use std::{
+    marker::PhantomData,
+    ops::Index,
+    hash::Hash,
+    slice::Iter,
+    iter::Copied,
+};
+use crate::{
+    prelude::*,
+    iter_type::Sequential,
+    mutable::validate::assert_unique,
+};
+#[cfg(feature = "rayon")]
+use crate::iter_type::Parallel;
+
+/// A reference to a collection, or a tuple of references to collections,
+/// that can be selected from with [`select_zip_mut`].
+///
+/// This is implemented for `&mut C` where `C` is [`OneToOne`], which produces
+/// `&mut C::Output`, for `&C` where `C` is [`Index`], which produces `&C::Output`,
+/// and for tuples of up to 8 sources, which produce a tuple of their items.
+///
+/// # Safety
+/// [`fetch`](ZipSource::fetch) must only produce mutable references that are
+/// unique for every unique index, as described by [`OneToOne`].
+pub unsafe trait ZipSource<'a, Idx>
+where
+    Idx: Copy,
+{
+    /// The type produced for each index.
+    type Item;
+    /// The pointer form of this source, used while iterating.
+    type Ptr: Copy;
+
+    /// Returns the pointer form of this source.
+    fn as_ptr(&mut self) -> Self::Ptr;
+
+    /// Produces the item at `index`.
+    ///
+    /// # Safety
+    /// `ptr` must come from [`as_ptr`](ZipSource::as_ptr), and this must
+    /// never be called twice with the same index on the same source.
+    unsafe fn fetch(ptr: Self::Ptr, index: Idx) -> Self::Item;
+}
+
+unsafe impl<'a, Data, Idx> ZipSource<'a, Idx> for &'a mut Data
+where
+    Data: ?Sized + OneToOne<Idx> + 'a,
+    Data::Output: 'a,
+    Idx: Copy,
+{
+    type Item = &'a mut Data::Output;
+    type Ptr = *mut Data;
+
+    fn as_ptr(&mut self) -> Self::Ptr {
+        *self
+    }
+
+    unsafe fn fetch(ptr: Self::Ptr, index: Idx) -> Self::Item {
+        ptr.as_mut().unwrap().index_mut(index)
+    }
+}
+
+unsafe impl<'a, Data, Idx> ZipSource<'a, Idx> for &'a Data
+where
+    Data: ?Sized + Index<Idx> + 'a,
+    Data::Output: 'a,
+    Idx: Copy,
+{
+    type Item = &'a Data::Output;
+    type Ptr = *const Data;
+
+    fn as_ptr(&mut self) -> Self::Ptr {
+        *self
+    }
+
+    unsafe fn fetch(ptr: Self::Ptr, index: Idx) -> Self::Item {
+        ptr.as_ref().unwrap().index(index)
+    }
+}
+
+macro_rules! impl_zip_source_tuple {
+    ($($source:ident $field:tt),+) => {
+        unsafe impl<'a, Idx, $($source),+> ZipSource<'a, Idx> for ($($source,)+)
+        where
+            Idx: Copy,
+            $($source: ZipSource<'a, Idx>,)+
+        {
+            type Item = ($($source::Item,)+);
+            type Ptr = ($($source::Ptr,)+);
+
+            fn as_ptr(&mut self) -> Self::Ptr {
+                ($(self.$field.as_ptr(),)+)
+            }
+
+            unsafe fn fetch(ptr: Self::Ptr, index: Idx) -> Self::Item {
+                ($($source::fetch(ptr.$field, index),)+)
+            }
+        }
+    };
+}
+
+impl_zip_source_tuple!(A 0);
+impl_zip_source_tuple!(A 0, B 1);
+impl_zip_source_tuple!(A 0, B 1, C 2);
+impl_zip_source_tuple!(A 0, B 1, C 2, D 3);
+impl_zip_source_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_zip_source_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_zip_source_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_zip_source_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Iterates, with a set of indices, through multiple collections at once.
+pub struct SelectZipIter<'a, Sources, Indices, IterType>
+{
+    pub(crate) sources: Sources,
+    pub(crate) indices: Indices,
+    pub(crate) _phantom: PhantomData<(&'a (), IterType)>,
+}
+
+/// Return type for [`select_zip_mut`].
+pub type SeqSelectZipIter<'a, Sources, Indices> = SelectZipIter<'a, Sources, Indices, Sequential>;
+#[cfg(feature = "rayon")]
+/// Return type for [`par_select_zip_mut`].
+pub type ParSelectZipIter<'a, Sources, Indices> = SelectZipIter<'a, Sources, Indices, Parallel>;
+
+/// Iterate through multiple collections with one slice of indices.
+///
+/// `sources` is a tuple of up to 8 collection references. Every `&mut` reference
+/// produces `&mut` elements and every `&` reference produces `&` elements, so
+/// `(&mut a, &mut b, &c)` produces `(&mut A, &mut B, &C)` for each index.
+///
+/// The indices are checked for uniqueness once, the same way as
+/// [`select_indices_mut`](crate::SelectIndicesMut::select_indices_mut).
+///
+/// ```rust
+/// use select_indices::prelude::*;
+///
+/// let mut positions = vec![0.0, 0.0, 0.0, 0.0];
+/// let velocities = vec![1.0, 2.0, 3.0, 4.0];
+///
+/// select_zip_mut((&mut positions, &velocities), &[3, 1])
+///     .for_each(|(position, velocity)| *position += velocity);
+///
+/// assert_eq!(positions, [0.0, 2.0, 0.0, 4.0]);
+/// ```
+pub fn select_zip_mut<'a, Sources, Idx>(sources: Sources, indices: &'a [Idx]) -> SeqSelectZipIter<'a, Sources, Copied<Iter<'a, Idx>>>
+where
+    Sources: ZipSource<'a, Idx>,
+    Idx: Eq + Hash + Copy,
+{
+    assert_unique(indices, "select_zip_mut");
+
+    SeqSelectZipIter {
+        sources,
+        indices: indices.iter().copied(),
+        _phantom: Default::default(),
+    }
+}
+
+impl<'a, Sources, Indices> Iterator for SeqSelectZipIter<'a, Sources, Indices>
+where
+    Sources: ZipSource<'a, Indices::Item>,
+    Indices: Iterator,
+    Indices::Item: Copy,
+{
+    type Item = Sources::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sources = self.sources.as_ptr();
+        self.indices.next().map(|index| {
+            unsafe { Sources::fetch(sources, index) }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<'a, Sources, Indices> DoubleEndedIterator for SeqSelectZipIter<'a, Sources, Indices>
+where
+    Sources: ZipSource<'a, Indices::Item>,
+    Indices: DoubleEndedIterator,
+    Indices::Item: Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let sources = self.sources.as_ptr();
+        self.indices.next_back().map(|index| {
+            unsafe { Sources::fetch(sources, index) }
+        })
+    }
+}
+
+impl<'a, Sources, Indices> ExactSizeIterator for SeqSelectZipIter<'a, Sources, Indices>
+where
+    Sources: ZipSource<'a, Indices::Item>,
+    Indices: ExactSizeIterator,
+    Indices::Item: Copy,
+{}
+
+#[cfg(feature = "rayon")]
+mod parallel {
+    use std::hash::Hash;
+    use crate::mutable::validate::assert_unique;
+    use super::{ ZipSource, ParSelectZipIter };
+    use ::rayon::{
+        prelude::*,
+        slice::Iter,
+        iter::Copied,
+    };
+
+    /// Iterate through multiple collections with one slice of indices.
+    ///
+    /// Parallel form of [`select_zip_mut`](crate::select_zip_mut).
+    pub fn par_select_zip_mut<'a, Sources, Idx>(sources: Sources, indices: &'a [Idx]) -> ParSelectZipIter<'a, Sources, Copied<Iter<'a, Idx>>>
+    where
+        Sources: ZipSource<'a, Idx> + Send,
+        Idx: Eq + Hash + Copy + Sync + Send,
+    {
+        assert_unique(indices, "par_select_zip_mut");
+
+        ParSelectZipIter {
+            sources,
+            indices: indices.into_par_iter().copied(),
+            _phantom: Default::default(),
+        }
+    }
+}
+#[cfg(feature = "rayon")]
+pub use self::parallel::par_select_zip_mut;
\ No newline at end of file
@@ -0,0 +1,315 @@
+use std::{
+    ops::Range,
+    slice,
+    iter::Copied,
+    vec,
+    cmp::Ordering,
+};
+use crate::UniqueIndexIter;
+
+/// A sorted set of unique `usize` indices, built from common shapes and
+/// combined with set algebra.
+///
+/// Iterating a pattern produces its indices in ascending order, and implements
+/// [`UniqueIndexIter`], so a pattern can be passed to
+/// [`select_unique_iter_mut`](crate::SelectIndicesMut::select_unique_iter_mut)
+/// without any duplicate checks.
+///
+/// ```rust
+/// use select_indices::prelude::*;
+///
+/// // The border of a 4x4 grid, without its corners.
+/// let grid = IndexPattern::block(4, 0..4, 0..4);
+/// let inner = IndexPattern::block(4, 1..3, 1..3);
+/// let corners = IndexPattern::from_indices(vec![0, 3, 12, 15]);
+/// let edges = grid.difference(&inner).difference(&corners);
+///
+/// let mut data = [0; 16];
+/// data.select_unique_iter_mut(&edges).for_each(|x| *x = 1);
+///
+/// assert_eq!(data, [
+///     0, 1, 1, 0,
+///     1, 0, 0, 1,
+///     1, 0, 0, 1,
+///     0, 1, 1, 0,
+/// ]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IndexPattern
+{
+    pub(crate) indices: Vec<usize>,
+}
+
+impl IndexPattern
+{
+    /// A pattern with no indices.
+    pub fn empty() -> Self {
+        IndexPattern::default()
+    }
+
+    /// A pattern of any indices, which are sorted and deduplicated.
+    pub fn from_indices<Indices>(indices: Indices) -> Self
+    where
+        Indices: IntoIterator<Item = usize>,
+    {
+        let mut indices: Vec<usize> = indices.into_iter().collect();
+        indices.sort_unstable();
+        indices.dedup();
+        IndexPattern { indices }
+    }
+
+    /// Every index in `range`.
+    pub fn range(range: Range<usize>) -> Self {
+        IndexPattern { indices: range.collect() }
+    }
+
+    /// Every `step`-th index in `range`, starting at `range.start`.
+    ///
+    /// This panics if `step` is 0.
+    pub fn strided(range: Range<usize>, step: usize) -> Self {
+        assert!(step != 0, "IndexPattern::strided was passed a step of 0!");
+        IndexPattern { indices: range.step_by(step).collect() }
+    }
+
+    /// Every `n`-th index of a collection of length `len`, starting at 0.
+    ///
+    /// This panics if `n` is 0.
+    pub fn every_nth(n: usize, len: usize) -> Self {
+        assert!(n != 0, "IndexPattern::every_nth was passed an n of 0!");
+        IndexPattern::strided(0..len, n)
+    }
+
+    /// The indices of a rectangular block of a row-major 2D collection, where
+    /// each row has `width` elements.
+    ///
+    /// This panics if `cols` reaches past the end of a row, or if an index
+    /// would overflow `usize`.
+    pub fn block(width: usize, rows: Range<usize>, cols: Range<usize>) -> Self {
+        assert!(
+            cols.end <= width || cols.is_empty(),
+            "IndexPattern::block was passed columns up to {} for a width of {}!", cols.end, width,
+        );
+        IndexPattern {
+            indices: rows
+                .flat_map(|row| cols.clone().map(move |col| grid_index(width, row, col, "IndexPattern::block")))
+                .collect(),
+        }
+    }
+
+    /// The indices of the main diagonal of a row-major 2D collection with
+    /// `rows` rows of `width` elements each.
+    ///
+    /// This panics if an index would overflow `usize`.
+    pub fn diagonal(width: usize, rows: usize) -> Self {
+        IndexPattern {
+            indices: (0..width.min(rows)).map(|i| grid_index(width, i, i, "IndexPattern::diagonal")).collect(),
+        }
+    }
+
+    /// The indices that are in either pattern.
+    pub fn union(&self, other: &IndexPattern) -> Self {
+        self.merge(other, true, true, true)
+    }
+
+    /// The indices that are in both patterns.
+    pub fn intersection(&self, other: &IndexPattern) -> Self {
+        self.merge(other, false, true, false)
+    }
+
+    /// The indices that are in this pattern but not in `other`.
+    pub fn difference(&self, other: &IndexPattern) -> Self {
+        self.merge(other, true, false, false)
+    }
+
+    /// The indices that are in exactly one of the patterns.
+    pub fn symmetric_difference(&self, other: &IndexPattern) -> Self {
+        self.merge(other, true, false, true)
+    }
+
+    /// Walks both sorted index lists at once, keeping the indices found only in
+    /// `self`, in both, or only in `other`, as requested.
+    fn merge(&self, other: &IndexPattern, keep_self: bool, keep_both: bool, keep_other: bool) -> Self {
+        let (a, b) = (&self.indices, &other.indices);
+        let mut indices = Vec::with_capacity(a.len() + b.len());
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() && j < b.len() {
+            match a[i].cmp(&b[j]) {
+                Ordering::Less => {
+                    if keep_self { indices.push(a[i]); }
+                    i += 1;
+                },
+                Ordering::Greater => {
+                    if keep_other { indices.push(b[j]); }
+                    j += 1;
+                },
+                Ordering::Equal => {
+                    if keep_both { indices.push(a[i]); }
+                    i += 1;
+                    j += 1;
+                },
+            }
+        }
+        if keep_self { indices.extend_from_slice(&a[i..]); }
+        if keep_other { indices.extend_from_slice(&b[j..]); }
+        IndexPattern { indices }
+    }
+
+    /// Returns the number of indices in the pattern.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns `true` if the pattern has no indices.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns `true` if the pattern contains `index`.
+    pub fn contains(&self, index: usize) -> bool {
+        self.indices.binary_search(&index).is_ok()
+    }
+
+    /// Returns the indices of the pattern in ascending order.
+    pub fn as_slice(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Iterates through the indices of the pattern in ascending order.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter { inner: self.indices.iter().copied() }
+    }
+}
+
+/// Returns the index of `(row, col)` in a row-major 2D collection, panicking
+/// instead of wrapping around, so that patterns stay sorted and unique.
+fn grid_index(width: usize, row: usize, col: usize, caller: &str) -> usize
+{
+    row.checked_mul(width)
+        .and_then(|start| start.checked_add(col))
+        .unwrap_or_else(|| panic!("{} was passed a shape whose indices overflow usize!", caller))
+}
+
+/// Iterates through the indices of an [`IndexPattern`] in ascending order.
+pub struct Iter<'a>
+{
+    inner: Copied<slice::Iter<'a, usize>>,
+}
+
+/// Iterates through the indices of an owned [`IndexPattern`] in ascending order.
+pub struct IntoIter
+{
+    inner: vec::IntoIter<usize>,
+}
+
+macro_rules! impl_pattern_iter {
+    ($($iter:ty),+) => {
+        $(
+            impl Iterator for $iter {
+                type Item = usize;
+
+                fn next(&mut self) -> Option<usize> {
+                    self.inner.next()
+                }
+
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    self.inner.size_hint()
+                }
+            }
+
+            impl DoubleEndedIterator for $iter {
+                fn next_back(&mut self) -> Option<usize> {
+                    self.inner.next_back()
+                }
+            }
+
+            impl ExactSizeIterator for $iter {}
+
+            // Safety: The indices of a pattern are always sorted and deduplicated.
+            unsafe impl UniqueIndexIter for $iter {}
+        )+
+    };
+}
+
+impl_pattern_iter!(Iter<'_>, IntoIter);
+
+impl<'a> IntoIterator for &'a IndexPattern
+{
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl IntoIterator for IndexPattern
+{
+    type Item = usize;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> IntoIter {
+        IntoIter { inner: self.indices.into_iter() }
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod parallel {
+    use super::IndexPattern;
+    use crate::UniqueIndexIter;
+    use ::rayon::{
+        prelude::*,
+        slice,
+        iter::{
+            Copied,
+            plumbing::{ Consumer, UnindexedConsumer, ProducerCallback },
+        },
+    };
+
+    /// Iterates in parallel through the indices of an [`IndexPattern`].
+    pub struct ParIter<'a>
+    {
+        inner: Copied<slice::Iter<'a, usize>>,
+    }
+
+    // Safety: The indices of a pattern are always sorted and deduplicated.
+    unsafe impl UniqueIndexIter for ParIter<'_> {}
+
+    impl ParallelIterator for ParIter<'_>
+    {
+        type Item = usize;
+
+        fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where
+            C: UnindexedConsumer<Self::Item>
+        {
+            self.inner.drive_unindexed(consumer)
+        }
+    }
+
+    impl IndexedParallelIterator for ParIter<'_>
+    {
+        fn len(&self) -> usize {
+            self.inner.len()
+        }
+
+        fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+            self.inner.drive(consumer)
+        }
+
+        fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+            self.inner.with_producer(callback)
+        }
+    }
+
+    impl<'a> IntoParallelIterator for &'a IndexPattern
+    {
+        type Item = usize;
+        type Iter = ParIter<'a>;
+
+        fn into_par_iter(self) -> ParIter<'a> {
+            ParIter { inner: self.indices.par_iter().copied() }
+        }
+    }
+}
+#[cfg(feature = "rayon")]
+pub use self::parallel::ParIter;
\ No newline at end of file
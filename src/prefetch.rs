@@ -0,0 +1,163 @@
+use std::collections::VecDeque;
+
+/// A collection that can report the address of the element at an index,
+/// without reading it, so that the element can be prefetched.
+///
+/// This is implemented for slices, arrays and [`Vec`]s indexed by `usize`.
+/// Implement it for your own collection to call `prefetch` on the iterators
+/// returned by [`select_indices`](crate::SelectIndices::select_indices) and
+/// [`select_indices_mut`](crate::SelectIndicesMut::select_indices_mut) with it.
+pub trait PrefetchIndex<Idx>
+{
+    /// Returns the address of the element at `index` in the collection behind
+    /// `data`, or `None` if `index` is out of bounds. This must never panic.
+    ///
+    /// # Safety
+    ///
+    /// `data` must point to a live collection whose elements may be mutably
+    /// borrowed elsewhere. Implementations must therefore never create a
+    /// reference to an element, only compute its address.
+    unsafe fn prefetch_address(data: *const Self, index: Idx) -> Option<*const u8>;
+}
+
+impl<T> PrefetchIndex<usize> for [T]
+{
+    unsafe fn prefetch_address(data: *const Self, index: usize) -> Option<*const u8> {
+        // A slice of `()` has the same length, and referencing it touches no
+        // element of type `T`.
+        let len = <[()]>::len(&*(data as *const [()]));
+        if index < len {
+            Some((data as *const T).wrapping_add(index).cast())
+        }
+        else {
+            None
+        }
+    }
+}
+
+impl<T, const N: usize> PrefetchIndex<usize> for [T; N]
+{
+    unsafe fn prefetch_address(data: *const Self, index: usize) -> Option<*const u8> {
+        if index < N {
+            Some((data as *const T).wrapping_add(index).cast())
+        }
+        else {
+            None
+        }
+    }
+}
+
+impl<T> PrefetchIndex<usize> for Vec<T>
+{
+    unsafe fn prefetch_address(data: *const Self, index: usize) -> Option<*const u8> {
+        // This borrows the vector's pointer and length, which are not part of
+        // the heap buffer that holds the elements.
+        let (ptr, len) = ((*data).as_ptr(), (*data).len());
+        if index < len {
+            Some(ptr.wrapping_add(index).cast())
+        }
+        else {
+            None
+        }
+    }
+}
+
+/// Hints to the CPU that the memory at `address` will be read soon.
+///
+/// Prefetching never faults, so any address is allowed. This is a no-op on
+/// targets other than x86_64 and aarch64.
+#[inline(always)]
+pub(crate) fn prefetch_read(address: *const u8) {
+    #[cfg(target_arch = "x86_64")]
+    unsafe {
+        use std::arch::x86_64::{ _mm_prefetch, _MM_HINT_T0 };
+        _mm_prefetch::<_MM_HINT_T0>(address.cast());
+    }
+    #[cfg(target_arch = "aarch64")]
+    unsafe {
+        std::arch::asm!("prfm pldl1keep, [{0}]", in(reg) address, options(nostack, readonly, preserves_flags));
+    }
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    let _ = address;
+}
+
+/// Produces the indices of another iterator, while prefetching the element at
+/// the index `distance` places ahead of the one being produced.
+///
+/// Created by the `prefetch` methods of the sequential selection iterators.
+pub struct PrefetchIndices<Data, Indices>
+where
+    Data: ?Sized,
+    Indices: Iterator,
+{
+    pub(crate) data: *const Data,
+    pub(crate) indices: Indices,
+    pub(crate) upcoming: VecDeque<Indices::Item>,
+    pub(crate) distance: usize,
+}
+
+impl<Data, Indices> PrefetchIndices<Data, Indices>
+where
+    Data: ?Sized + PrefetchIndex<Indices::Item>,
+    Indices: Iterator,
+    Indices::Item: Copy,
+{
+    pub(crate) fn new(data: *const Data, indices: Indices, distance: usize) -> Self {
+        PrefetchIndices {
+            data,
+            indices,
+            upcoming: VecDeque::with_capacity(distance + 1),
+            distance,
+        }
+    }
+}
+
+impl<Data, Indices> Iterator for PrefetchIndices<Data, Indices>
+where
+    Data: ?Sized + PrefetchIndex<Indices::Item>,
+    Indices: Iterator,
+    Indices::Item: Copy,
+{
+    type Item = Indices::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.upcoming.len() <= self.distance {
+            match self.indices.next() {
+                Some(index) => {
+                    // Safety: The collection outlives the selection iterator that
+                    // owns these indices, and no reference to it is created here.
+                    if let Some(address) = unsafe { Data::prefetch_address(self.data, index) } {
+                        prefetch_read(address);
+                    }
+                    self.upcoming.push_back(index);
+                },
+                None => break,
+            }
+        }
+        self.upcoming.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.indices.size_hint();
+        let buffered = self.upcoming.len();
+        (lower.saturating_add(buffered), upper.and_then(|upper| upper.checked_add(buffered)))
+    }
+}
+
+impl<Data, Indices> DoubleEndedIterator for PrefetchIndices<Data, Indices>
+where
+    Data: ?Sized + PrefetchIndex<Indices::Item>,
+    Indices: DoubleEndedIterator,
+    Indices::Item: Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.indices.next_back().or_else(|| self.upcoming.pop_back())
+    }
+}
+
+impl<Data, Indices> ExactSizeIterator for PrefetchIndices<Data, Indices>
+where
+    Data: ?Sized + PrefetchIndex<Indices::Item>,
+    Indices: ExactSizeIterator,
+    Indices::Item: Copy,
+{}
\ No newline at end of file
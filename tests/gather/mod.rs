@@ -0,0 +1,162 @@
+use select_indices::prelude::*;
+
+/// The raw bits of a gathered element, so that NaNs compare equal.
+trait Bits: Copy
+{
+    fn bits(self) -> u64;
+}
+
+impl Bits for f32 { fn bits(self) -> u64 { self.to_bits() as u64 } }
+impl Bits for f64 { fn bits(self) -> u64 { self.to_bits() } }
+impl Bits for i32 { fn bits(self) -> u64 { self as u32 as u64 } }
+impl Bits for u32 { fn bits(self) -> u64 { self as u64 } }
+
+fn bits<T: Bits>(gathered: Vec<T>) -> Vec<u64>
+{
+    gathered.into_iter().map(Bits::bits).collect()
+}
+
+/// Scrambled indices into a collection of `len` elements, with repeats.
+fn scrambled(len: usize, count: usize) -> Vec<usize>
+{
+    (0..count).map(|i| (i * 7919 + 13) % len).collect()
+}
+
+/// The paths that this CPU and the enabled features should support, detected
+/// independently of `GatherPath::is_supported`.
+fn expected_paths() -> Vec<GatherPath>
+{
+    #[allow(unused_mut)]
+    let mut paths = vec![GatherPath::Scalar];
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            paths.push(GatherPath::Avx2);
+        }
+        #[cfg(feature = "avx512")]
+        {
+            if is_x86_feature_detected!("avx512f") {
+                paths.push(GatherPath::Avx512);
+            }
+        }
+    }
+    paths
+}
+
+/// Checks that every path the CPU supports gathers the same bits as the scalar
+/// path, and that the scalar path gathers the same bits as `select_indices`.
+/// Returns the paths that were compared.
+fn assert_paths_agree<T>(data: &[T], indices: &[usize]) -> Vec<GatherPath>
+where
+    T: Bits,
+    [T]: GatherSimd<usize, Item = T> + GatherSimd<u32, Item = T>,
+{
+    let short_indices: Vec<u32> = indices.iter().map(|&i| i as u32).collect();
+
+    let expected: Vec<u64> = data.select_indices(indices).map(|&x| x.bits()).collect();
+    let scalar = bits(data.gather_simd_with(indices, GatherPath::Scalar));
+    assert_eq!(scalar, expected);
+    assert_eq!(bits(data.gather_simd_with(&short_indices, GatherPath::Scalar)), expected);
+
+    let mut compared = vec![GatherPath::Scalar];
+    for &path in [GatherPath::Avx2, GatherPath::Avx512].iter().filter(|path| path.is_supported()) {
+        assert_eq!(bits(data.gather_simd_with(indices, path)), scalar, "{:?} with usize indices", path);
+        assert_eq!(bits(data.gather_simd_with(&short_indices, path)), scalar, "{:?} with u32 indices", path);
+        compared.push(path);
+    }
+    assert_eq!(bits(data.gather_simd(indices)), scalar);
+    compared
+}
+
+/// Fails unless every path this CPU supports was compared, so that a broken
+/// detection cannot make the tests pass without running the SIMD kernels.
+fn assert_compared_all(compared: Vec<GatherPath>)
+{
+    eprintln!("gather paths compared: {:?}", compared);
+    assert_eq!(compared, expected_paths());
+}
+
+#[test]
+fn gather_simd_f32()
+{
+    let data: Vec<f32> = (0..1000).map(|i| i as f32 * 0.25 - 100.0).collect();
+
+    // Every remainder length of the widest vector, plus a few full vectors.
+    for count in 0..40 {
+        assert_compared_all(assert_paths_agree(&data, &scrambled(data.len(), count)));
+    }
+}
+
+#[test]
+fn gather_simd_f64()
+{
+    let data: Vec<f64> = (0..1000).map(|i| i as f64 * -1.5).collect();
+
+    for count in 0..40 {
+        assert_compared_all(assert_paths_agree(&data, &scrambled(data.len(), count)));
+    }
+}
+
+#[test]
+fn gather_simd_integers()
+{
+    let signed: Vec<i32> = (0..1000).map(|i| i * 3 - 1500).collect();
+    let unsigned: Vec<u32> = (0..1000).map(|i| u32::MAX - i * 5).collect();
+
+    let indices = scrambled(1000, 301);
+    assert_compared_all(assert_paths_agree(&signed, &indices));
+    assert_compared_all(assert_paths_agree(&unsigned, &indices));
+}
+
+#[test]
+fn gather_simd_bit_identical()
+{
+    // NaN payloads, signed zeros and subnormals must be copied without change.
+    let floats: Vec<f32> = [0x7fc0_0001u32, 0xffbf_ffff, 0x8000_0000, 0x0000_0001, 0x7f80_0000]
+        .iter().map(|&bits| f32::from_bits(bits)).collect();
+    let doubles: Vec<f64> = [0x7ff0_0000_0000_0001u64, 0xfff8_dead_beef_0000, 0x8000_0000_0000_0000, 0x0000_0000_0000_0001]
+        .iter().map(|&bits| f64::from_bits(bits)).collect();
+
+    assert_compared_all(assert_paths_agree(&floats, &scrambled(floats.len(), 37)));
+    assert_compared_all(assert_paths_agree(&doubles, &scrambled(doubles.len(), 37)));
+}
+
+#[test]
+fn gather_path_detect()
+{
+    let path = GatherPath::detect();
+
+    assert!(path.is_supported());
+    assert!(GatherPath::Scalar.is_supported());
+    assert_eq!(Some(&path), expected_paths().last());
+    if GatherPath::Avx512.is_supported() {
+        assert_eq!(path, GatherPath::Avx512);
+    }
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds_panic()
+{
+    let data = [1.0f32; 16];
+
+    data.gather_simd(&[0usize, 1, 2, 3, 4, 5, 6, 16]); // Out of bounds in the last vector: should panic
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds_u32_panic()
+{
+    let data = [1u32; 4];
+
+    data.gather_simd(&[0u32, 4]); // Out of bounds: should panic
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds_scalar_panic()
+{
+    let data = [1.0f64; 4];
+
+    data.gather_simd_with(&[0usize, 9], GatherPath::Scalar); // Out of bounds: should panic
+}
\ No newline at end of file
@@ -79,6 +79,95 @@ fn indexed_halfway()
     assert_eq!(iter.next(), None);
 }
 
+#[test]
+fn select_owned_indices()
+{
+    fn evens(data: &[i32]) -> impl Iterator<Item = &i32>
+    {
+        let indices: Vec<usize> = (0..data.len()).step_by(2).collect();
+        data.select_owned_indices(indices)
+    }
+
+    let data = [1, 2, 3, 4, 5, 6, 7];
+
+    assert!(
+        evens(&data).eq(&[1, 3, 5, 7])
+    )
+}
+
+#[test]
+fn nested_select()
+{
+    let data = [0, 10, 20, 30, 40, 50, 60, 70];
+
+    let mut iter = data.select_indices(&[7, 1, 5, 3, 6]);
+    assert_eq!(iter.next(), Some(&70));
+
+    assert!(
+        iter.select(&[3, 0, 2]).indexed().eq([(6, &60), (1, &10), (3, &30)])
+    )
+}
+
+#[test]
+fn select_indices_localized()
+{
+    // A pseudo-random permutation with repeats, so the sorted order differs from the input.
+    let data: Vec<String> = (0..1000).map(|i| i.to_string()).collect();
+    let indices: Vec<usize> = (0..3000).map(|i| (i * 7919) % 1000).collect();
+
+    let localized: Vec<&String> = data.select_indices_localized(&indices).collect();
+
+    assert!(data.select_indices(&indices).eq(localized));
+    assert_eq!(data.select_indices_localized::<usize>(&[]).len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn localized_out_of_range_panic()
+{
+    let data = [1,2,3];
+
+    data.select_indices_localized(&[2, 3, 0]); // 3 is out of bounds: should panic
+}
+
+#[test]
+fn prefetch()
+{
+    let data: Vec<u32> = (0..4096).map(|i| i * 3).collect();
+    let indices: Vec<usize> = (0..4096).map(|i| (i * 1031) % 4096).collect();
+
+    for distance in [0, 1, 8, 5000] {
+        assert!(data.select_indices(&indices).prefetch(distance).eq(data.select_indices(&indices)));
+        assert!(data.select_indices(&indices).prefetch(distance).rev().eq(data.select_indices(&indices).rev()));
+        assert_eq!(data.select_indices(&indices).prefetch(distance).len(), 4096);
+    }
+
+    let mut iter = data.select_indices(&indices).indexed().prefetch(4);
+    assert_eq!(iter.next(), Some((0, &0)));
+    assert_eq!(iter.next_back(), Some((3065, &9195)));
+    assert_eq!(iter.len(), 4094);
+}
+
+#[test]
+fn prefetch_out_of_range()
+{
+    let data = [1, 2, 3];
+    let indices = [0, 5];
+
+    // Prefetching index 5 must not panic before index 0 is produced.
+    let mut iter = data.select_indices(&indices).prefetch(4);
+    assert_eq!(iter.next(), Some(&1));
+}
+
+#[test]
+#[should_panic]
+fn prefetch_out_of_range_panic()
+{
+    let data = [1, 2, 3];
+
+    data.select_indices(&[0, 5]).prefetch(4).for_each(|x| println!("{x}")); // 5 is out of bounds: should panic
+}
+
 #[cfg(feature = "rayon")]
 mod rayon;
 
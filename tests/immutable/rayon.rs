@@ -25,4 +25,31 @@ fn out_of_range_panic()
     let indices = [1,2,3];
 
     println!("{}", data.par_select_indices(&indices).eq(&[2,3,4]));
+}
+
+#[test]
+fn select_owned_indices_test()
+{
+    let data = [
+        0,0,2,0,1,
+        0,0,0,0,0,
+        0,0,4,5,3
+    ];
+
+    let indices = vec![4, 2, 14, 12, 13];
+
+    assert!(
+        data.par_select_owned_indices(indices).eq(&[1,2,3,4,5])
+    );
+}
+
+#[test]
+fn par_select_indices_localized()
+{
+    let data: Vec<u64> = (0..100_000).map(|i| i * 3).collect();
+    let indices: Vec<usize> = (0..200_000).map(|i| (i * 104_729) % 100_000).collect();
+
+    let localized: Vec<&u64> = data.par_select_indices_localized(&indices).collect();
+
+    assert!(data.select_indices(&indices).eq(localized));
 }
\ No newline at end of file
@@ -0,0 +1,90 @@
+use select_indices::prelude::*;
+
+// 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3, 4 -> 3, 4 -> 0
+const OFFSETS: [usize; 6] = [0, 2, 3, 4, 4, 6];
+const TARGETS: [usize; 6] = [1, 2, 3, 3, 3, 0];
+
+#[test]
+fn select_csr_mut()
+{
+    let mut data = [1, 10, 100, 1000, 10000];
+
+    data.select_csr_mut(&OFFSETS, &TARGETS, &[1, 2])
+        .for_each(|(node, neighbors)| *node += neighbors.sum::<i32>());
+
+    assert_eq!(data, [1, 1010, 1100, 1000, 10000]);
+}
+
+#[test]
+fn select_csr_mut_rev()
+{
+    let mut data = [1, 10, 100, 1000, 10000];
+    let mut order = Vec::new();
+
+    data.select_csr_mut(&OFFSETS, &TARGETS, &[0, 3]).rev()
+        .for_each(|(node, neighbors)| {
+            order.push(*node);
+            *node = neighbors.count() as i32;
+        });
+
+    assert_eq!(order, [1000, 1]);
+    assert_eq!(data, [2, 10, 100, 0, 10000]);
+}
+
+#[test]
+#[should_panic]
+fn frontier_is_neighbor_panic()
+{
+    let mut data = [0; 5];
+
+    data.select_csr_mut(&OFFSETS, &TARGETS, &[0, 4]) // 0 is a neighbour of 4: should panic
+        .for_each(|(node, _)| *node = 1);
+}
+
+#[test]
+#[should_panic]
+fn repeated_index_panic()
+{
+    let mut data = [0; 5];
+
+    data.select_csr_mut(&OFFSETS, &TARGETS, &[3, 3]) // Repeated index: should panic
+        .for_each(|(node, _)| *node = 1);
+}
+
+#[test]
+#[should_panic]
+fn out_of_range_panic()
+{
+    let mut data = [0; 5];
+
+    data.select_csr_mut(&OFFSETS, &TARGETS, &[5]) // 5 is not a node: should panic
+        .for_each(|(node, _)| *node = 1);
+}
+
+#[cfg(feature = "rayon")]
+mod rayon {
+    use select_indices::prelude::*;
+    use rayon::prelude::*;
+    use super::{ OFFSETS, TARGETS };
+
+    #[test]
+    fn par_select_csr_mut()
+    {
+        let mut data = [1, 10, 100, 1000, 10000];
+
+        data.par_select_csr_mut(&OFFSETS, &TARGETS, &[4, 1, 2])
+            .for_each(|(node, neighbors)| *node += neighbors.sum::<i32>());
+
+        assert_eq!(data, [1, 1010, 1100, 1000, 11001]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn frontier_is_neighbor_panic()
+    {
+        let mut data = [0; 5];
+
+        data.par_select_csr_mut(&OFFSETS, &TARGETS, &[1, 3]) // 3 is a neighbour of 1: should panic
+            .for_each(|(node, _)| *node = 1);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+use select_indices::prelude::*;
+
+#[test]
+fn disjoint_mut()
+{
+    let mut data = vec![String::from("a"), String::from("b"), String::from("c")];
+    let mut handle = data.disjoint_mut();
+
+    let first = handle.get_mut(2).unwrap();
+    first.push('!');
+    let second = handle.get_mut(0).unwrap();
+    second.push_str(first);
+
+    assert_eq!(handle.borrowed_len(), 2);
+    assert_eq!(data, ["ac!", "b", "c!"]);
+}
+
+#[test]
+fn already_borrowed()
+{
+    let mut data = [1, 2, 3];
+    let mut handle = data.disjoint_mut();
+
+    let a = handle.get_mut(1).unwrap();
+    assert_eq!(handle.get_mut(1), Err(AlreadyBorrowed));
+    assert_eq!(handle.borrowed_len(), 1);
+    *a = 0;
+
+    assert_eq!(AlreadyBorrowed.to_string(), "the element at this index is already mutably borrowed");
+    assert_eq!(data, [1, 0, 3]);
+}
+
+#[test]
+fn zero_sized_elements()
+{
+    let mut data = [(), (), ()];
+    let mut handle = data.disjoint_mut();
+
+    assert!(handle.get_mut(0).is_ok());
+    assert!(handle.get_mut(2).is_ok());
+    assert_eq!(handle.get_mut(0), Err(AlreadyBorrowed));
+    assert_eq!(handle.borrowed_len(), 2);
+}
+
+#[test]
+#[should_panic]
+fn out_of_range_panic()
+{
+    let mut data = [1, 2, 3];
+
+    let _ = data.disjoint_mut().get_mut(3); // 3 is out of bounds: should panic
+}
\ No newline at end of file
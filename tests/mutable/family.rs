@@ -0,0 +1,91 @@
+use select_indices::prelude::*;
+
+#[test]
+fn select_one_and_many_mut()
+{
+    let mut data = vec![1, 2, 3, 4, 5];
+
+    let (parent, children) = data.select_one_and_many_mut(2, &[4, 0, 1]);
+    for child in children {
+        *child *= *parent;
+        *parent += 1;
+    }
+
+    assert_eq!(data, [4, 10, 6, 4, 15]);
+}
+
+#[test]
+fn select_one_and_many_mut_no_children()
+{
+    let mut data = [1, 2, 3];
+
+    let (parent, mut children) = data.select_one_and_many_mut(1, &[]);
+    *parent = 0;
+
+    assert!(children.next().is_none());
+    assert_eq!(data, [1, 0, 3]);
+}
+
+#[test]
+#[should_panic]
+fn parent_is_child_panic()
+{
+    let mut data = [1, 2, 3];
+
+    data.select_one_and_many_mut(1, &[0, 1]); // 1 is its own child: should panic
+}
+
+#[test]
+#[should_panic]
+fn repeated_index_panic()
+{
+    let mut data = [1, 2, 3];
+
+    data.select_one_and_many_mut(1, &[2, 2]); // Repeated index: should panic
+}
+
+#[cfg(feature = "rayon")]
+mod rayon {
+    use select_indices::prelude::*;
+    use rayon::prelude::*;
+
+    #[test]
+    fn par_select_one_and_many_mut()
+    {
+        // Sibling subtrees 0 -> {1, 2, 3} and 4 -> {5, 6}, and a leaf 7.
+        let mut data = vec![10, 1, 2, 3, 20, 5, 6, 30];
+        let families: [(usize, &[usize]); 3] = [(0, &[1, 2, 3]), (4, &[5, 6]), (7, &[])];
+
+        data.par_select_one_and_many_mut(&families)
+            .for_each(|(parent, children)| {
+                children.for_each(|child| {
+                    *child += *parent;
+                    *parent += 1;
+                });
+            });
+
+        assert_eq!(data, [13, 11, 13, 15, 22, 25, 27, 30]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn shared_child_panic()
+    {
+        let mut data = [0; 5];
+        let families: [(usize, &[usize]); 2] = [(0, &[1, 2]), (3, &[2, 4])];
+
+        data.par_select_one_and_many_mut(&families) // 2 is in both families: should panic
+            .for_each(|(parent, _)| *parent = 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn parent_is_child_panic()
+    {
+        let mut data = [0; 5];
+        let families: [(usize, &[usize]); 1] = [(0, &[1, 0])];
+
+        data.par_select_one_and_many_mut(&families) // 0 is its own child: should panic
+            .for_each(|(parent, _)| *parent = 1);
+    }
+}
\ No newline at end of file
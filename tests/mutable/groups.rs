@@ -0,0 +1,74 @@
+use select_indices::prelude::*;
+
+#[test]
+fn select_groups_mut()
+{
+    let mut data = [1, 2, 3, 4, 5, 6];
+    let groups = [vec![5, 0], vec![], vec![2, 3, 1]];
+    let mut sizes = Vec::new();
+
+    data.select_groups_mut(&groups).for_each(|group| {
+        sizes.push(group.len());
+        group.enumerate().for_each(|(i, x)| *x *= 10_i32.pow(i as u32));
+    });
+
+    assert_eq!(sizes, [2, 0, 3]);
+    assert_eq!(data, [10, 200, 3, 40, 5, 6]);
+}
+
+#[test]
+fn select_groups_mut_rev()
+{
+    let mut data = [0; 4];
+    let groups: [&[usize]; 2] = [&[0, 1], &[3]];
+
+    data.select_groups_mut(&groups).rev()
+        .enumerate()
+        .for_each(|(g, group)| group.for_each(|x| *x = g + 1));
+
+    assert_eq!(data, [2, 2, 0, 1]);
+}
+
+#[test]
+#[should_panic]
+fn overlapping_groups_panic()
+{
+    let mut data = [1, 2, 3];
+
+    data.select_groups_mut(&[vec![0, 1], vec![1]]) // 1 is in both groups: should panic
+        .for_each(|group| group.for_each(|x| *x = 0));
+}
+
+#[cfg(feature = "rayon")]
+mod rayon {
+    use select_indices::prelude::*;
+    use rayon::prelude::*;
+
+    #[test]
+    fn par_select_groups_mut()
+    {
+        // Per-cluster mean, with clusters of interleaved indices.
+        let mut data: Vec<f64> = (0..100).map(|i| i as f64).collect();
+        let clusters: Vec<Vec<usize>> = (0..4).map(|c| (0..100).filter(|i| i % 4 == c).collect()).collect();
+
+        data.par_select_groups_mut(&clusters).for_each(|cluster| {
+            let mut cluster: Vec<&mut f64> = cluster.collect();
+            let mean = cluster.iter().map(|x| **x).sum::<f64>() / cluster.len() as f64;
+            cluster.iter_mut().for_each(|x| **x = mean);
+        });
+
+        for (i, x) in data.iter().enumerate() {
+            assert_eq!(*x, 48.0 + (i % 4) as f64);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn overlapping_groups_panic()
+    {
+        let mut data = [1, 2, 3];
+
+        data.par_select_groups_mut(&[[0, 2], [2, 1]]) // 2 is in both groups: should panic
+            .for_each(|group| group.for_each(|x| *x = 0));
+    }
+}
\ No newline at end of file
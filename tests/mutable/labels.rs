@@ -0,0 +1,83 @@
+use select_indices::prelude::*;
+use rayon::prelude::*;
+
+#[test]
+fn par_select_by_labels_mut()
+{
+    let mut data: Vec<usize> = (0..10).collect();
+    let labels = [3, 1, 3, 3, 1, 0, 5, 5, 1, 3];
+
+    let classes: Vec<(u32, Vec<usize>)> = data.par_select_by_labels_mut(&labels)
+        .map(|(label, class)| (label, class.map(|x| *x).collect()))
+        .collect();
+
+    assert_eq!(classes, [
+        (0, vec![5]),
+        (1, vec![1, 4, 8]),
+        (3, vec![0, 2, 3, 9]),
+        (5, vec![6, 7]),
+    ]);
+}
+
+#[test]
+fn par_select_by_labels_mut_large_labels()
+{
+    let mut data = vec![0_u64; 6];
+    let labels = [u32::MAX, 7, 1_000_000, 7, u32::MAX, 1_000_000];
+
+    let selection = data.par_select_by_labels_mut(&labels);
+    assert_eq!(selection.labels().collect::<Vec<_>>(), [7, 1_000_000, u32::MAX]);
+
+    selection.for_each(|(label, class)| {
+        class.enumerate().for_each(|(k, x)| *x = label as u64 * 10 + k as u64);
+    });
+
+    let max = u32::MAX as u64 * 10;
+    assert_eq!(data, [max, 70, 10_000_000, 71, max + 1, 10_000_001]);
+}
+
+#[test]
+fn par_select_by_labels_mut_sum()
+{
+    // Per-class sums, running each class as its own task.
+    let mut data: Vec<u64> = (0..10_000).collect();
+    let labels: Vec<u32> = (0..10_000).map(|i| (i % 7) as u32).collect();
+
+    let mut sums: Vec<(u32, u64)> = data.par_select_by_labels_mut(&labels)
+        .map(|(label, class)| (label, class.map(|x| *x).sum()))
+        .collect();
+    sums.sort_unstable();
+
+    for (label, sum) in sums {
+        let expected: u64 = (0..10_000).filter(|i| i % 7 == label as u64).sum();
+        assert_eq!(sum, expected);
+    }
+}
+
+#[test]
+fn par_select_by_labels_mut_empty()
+{
+    let mut data: Vec<i32> = Vec::new();
+
+    assert_eq!(data.par_select_by_labels_mut(&[]).count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn too_few_labels_panic()
+{
+    let mut data = vec![1, 2, 3, 4];
+
+    data.par_select_by_labels_mut(&[0, 1, 0]) // No label for the last element: should panic
+        .for_each(|(_, class)| class.for_each(|x| *x = 0));
+}
+
+#[test]
+#[should_panic]
+fn too_many_labels_panic()
+{
+    let mut data = [1, 2, 3];
+
+    data.par_select_by_labels_mut(&[0, 1, 0, 1]) // One label too many: should panic
+        .for_each(|(_, class)| class.for_each(|x| *x = 0));
+}
\ No newline at end of file
@@ -107,6 +107,172 @@ fn indexed_halfway()
     assert_eq!(iter.next(), None);
 }
 
+#[test]
+fn select_owned_indices_mut()
+{
+    fn evens(data: &mut [i32]) -> impl Iterator<Item = &mut i32>
+    {
+        let indices: Vec<usize> = (0..data.len()).step_by(2).collect();
+        data.select_owned_indices_mut(indices)
+    }
+
+    let mut data = [1, 2, 3, 4, 5, 6, 7];
+
+    evens(&mut data).for_each(|x| *x *= 10);
+
+    assert_eq!(data, [10, 2, 30, 4, 50, 6, 70]);
+}
+
+#[test]
+#[should_panic]
+fn owned_repeated_index_panic()
+{
+    let mut data = [1,2,3];
+
+    data.select_owned_indices_mut(vec![0, 2, 0]) // Repeated index: should panic
+        .for_each(|x| println!("{x}"));
+}
+
+#[test]
+fn replace_indices()
+{
+    let mut data = vec![0, 10, 20, 30, 40];
+
+    let old = data.replace_indices(&[3, 0], [33, 1]);
+
+    assert_eq!(old, [30, 0]);
+    assert_eq!(data, [1, 10, 20, 33, 40]);
+}
+
+#[test]
+#[should_panic]
+fn replace_value_count_panic()
+{
+    let mut data = [1,2,3];
+
+    data.replace_indices(&[0, 1], [5]); // Missing value: should panic
+}
+
+#[test]
+fn swap_selections()
+{
+    let mut data = [0, 1, 2, 3, 4, 5];
+
+    data.swap_selections(&[0, 1], &[5, 3]);
+
+    assert_eq!(data, [5, 3, 2, 1, 4, 0]);
+}
+
+#[test]
+#[should_panic]
+fn swap_selections_overlap_panic()
+{
+    let mut data = [0, 1, 2, 3];
+
+    data.swap_selections(&[0, 1], &[2, 0]); // 0 is in both selections: should panic
+}
+
+#[test]
+fn swap_between()
+{
+    let mut a = vec!["a0", "a1", "a2"];
+    let mut b = ["b0", "b1", "b2", "b3"];
+
+    a.swap_between(&[2, 0], &mut b, &[1, 3]);
+
+    assert_eq!(a, ["b3", "a1", "b1"]);
+    assert_eq!(b, ["b0", "a2", "b2", "a0"]);
+}
+
+#[test]
+fn nested_select()
+{
+    let mut data = [0, 10, 20, 30, 40, 50, 60, 70];
+
+    data.select_indices_mut(&[7, 1, 5, 3, 6])
+        .select(&[4, 0, 2])
+        .for_each(|x| *x += 1);
+    data.select_with_iter_mut(vec![2, 4, 0])
+        .select(&[1, 2])
+        .for_each(|x| *x += 2);
+
+    assert_eq!(data, [2, 10, 20, 30, 42, 51, 61, 71]);
+}
+
+#[test]
+#[should_panic]
+fn nested_repeated_index_panic()
+{
+    let mut data = [1,2,3];
+
+    data.select_indices_mut(&[0, 1, 2])
+        .select(&[1, 1]) // Repeated index: should panic
+        .for_each(|x| println!("{x}"));
+}
+
+#[test]
+#[should_panic]
+fn nested_from_iter_repeated_index_panic()
+{
+    let mut data = [1,2,3];
+
+    data.select_with_iter_mut(vec![0, 1, 0])
+        .select(&[0, 2]) // Repeated index in the outer iterator: should panic
+        .for_each(|x| println!("{x}"));
+}
+
+#[test]
+fn prefetch()
+{
+    let mut data: Vec<u64> = (0..4096).collect();
+    let indices: Vec<usize> = (0..2048).map(|i| (i * 1031) % 4096).collect();
+
+    data.select_indices_mut(&indices).prefetch(16).for_each(|x| *x += 10_000);
+    data.select_with_iter_mut(indices.iter().copied()).prefetch(3).indexed().for_each(|(i, x)| *x -= i as u64);
+
+    for (i, x) in data.iter().enumerate() {
+        let expected = if indices.contains(&i) { 10_000 } else { i as u64 };
+        assert_eq!(*x, expected);
+    }
+}
+
+#[test]
+#[should_panic]
+fn prefetch_repeated_index_panic()
+{
+    let mut data = [1,2,3];
+
+    data.select_with_iter_mut(vec![0, 2, 0]).prefetch(8) // Repeated index: should panic
+        .for_each(|x| println!("{x}"));
+}
+
+mod vec;
+
+mod selection;
+
+mod zip;
+
+mod neighbors;
+
+mod pairs;
+
+mod csr;
+
+mod family;
+
+mod disjoint;
+
+mod read_write;
+
+mod split;
+
+mod groups;
+
+mod unique;
+
+#[cfg(feature = "rayon")]
+mod labels;
+
 #[cfg(feature = "rayon")]
 mod rayon;
 
@@ -0,0 +1,79 @@
+use select_indices::prelude::*;
+
+const WIDTH: usize = 4;
+
+fn grid_neighbors(i: usize) -> impl Iterator<Item = usize>
+{
+    let (x, y) = ((i % WIDTH) as isize, (i / WIDTH) as isize);
+    [(-1, 0), (1, 0), (0, -1), (0, 1)].iter()
+        .map(move |&(dx, dy)| (x + dx, y + dy))
+        .filter(|&(x, y)| (0..WIDTH as isize).contains(&x) && (0..WIDTH as isize).contains(&y))
+        .map(|(x, y)| y as usize * WIDTH + x as usize)
+}
+
+#[test]
+fn select_with_neighbors_mut()
+{
+    // Checkerboard update: no black cell is a neighbour of another black cell.
+    let mut data: Vec<usize> = (0..WIDTH * WIDTH).collect();
+    let black: Vec<usize> = (0..WIDTH * WIDTH).filter(|i| (i % WIDTH + i / WIDTH) % 2 == 0).collect();
+
+    data.select_with_neighbors_mut(&black, grid_neighbors)
+        .for_each(|(cell, neighbors)| *cell = neighbors.sum());
+
+    assert_eq!(data[0], 1 + 4);
+    assert_eq!(data[1], 1);
+    assert_eq!(data[5], 1 + 4 + 6 + 9);
+    assert_eq!(data[15], 11 + 14);
+}
+
+#[test]
+#[should_panic]
+fn neighbor_is_center_panic()
+{
+    let mut data = [1, 2, 3, 4];
+
+    data.select_with_neighbors_mut(&[1, 2], |i| vec![i - 1, i + 1]) // 2 is a neighbour of 1: should panic
+        .for_each(|(cell, neighbors)| *cell = neighbors.sum());
+}
+
+#[test]
+#[should_panic]
+fn repeated_index_panic()
+{
+    let mut data = [1, 2, 3, 4];
+
+    data.select_with_neighbors_mut(&[1, 1], |_| Vec::new()) // Repeated index: should panic
+        .for_each(|(cell, _)| *cell = 0);
+}
+
+#[cfg(feature = "rayon")]
+mod rayon {
+    use select_indices::prelude::*;
+    use rayon::prelude::*;
+    use super::{ WIDTH, grid_neighbors };
+
+    #[test]
+    fn select_with_neighbors_mut()
+    {
+        let mut data: Vec<usize> = (0..WIDTH * WIDTH).collect();
+        let white: Vec<usize> = (0..WIDTH * WIDTH).filter(|i| (i % WIDTH + i / WIDTH) % 2 == 1).collect();
+
+        data.par_select_with_neighbors_mut(&white, grid_neighbors)
+            .for_each(|(cell, neighbors)| *cell = neighbors.sum());
+
+        assert_eq!(data[0], 0);
+        assert_eq!(data[1], 2 + 5);
+        assert_eq!(data[6], 2 + 5 + 7 + 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn neighbor_is_center_panic()
+    {
+        let mut data = [1, 2, 3, 4];
+
+        data.par_select_with_neighbors_mut(&[0, 2], |i| vec![i, i + 1]) // Own index as neighbour: should panic
+            .for_each(|(cell, neighbors)| *cell = neighbors.sum());
+    }
+}
\ No newline at end of file
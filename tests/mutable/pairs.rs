@@ -0,0 +1,138 @@
+use select_indices::prelude::*;
+
+#[test]
+fn select_pairs_mut()
+{
+    let mut data = [0, 1, 2, 3, 4, 5];
+
+    data.select_pairs_mut(&[(0, 5), (3, 1)])
+        .for_each(|(a, b)| std::mem::swap(a, b));
+
+    assert_eq!(data, [5, 3, 2, 1, 4, 0]);
+}
+
+#[test]
+#[should_panic]
+fn self_pair_panic()
+{
+    let mut data = [0, 1, 2];
+
+    data.select_pairs_mut(&[(0, 1), (2, 2)]) // Pair from 2 to itself: should panic
+        .for_each(|(a, b)| std::mem::swap(a, b));
+}
+
+#[test]
+#[should_panic]
+fn shared_index_panic()
+{
+    let mut data = [0, 1, 2];
+
+    data.select_pairs_mut(&[(0, 1), (1, 2)]) // 1 is in two pairs: should panic
+        .for_each(|(a, b)| std::mem::swap(a, b));
+}
+
+#[test]
+fn for_each_pair_mut()
+{
+    let mut data = [1, 10, 100, 1000, 10000];
+    let mut visited = Vec::new();
+
+    data.for_each_pair_mut(&[3, 0, 4], |a, b| {
+        visited.push((*a, *b));
+        *a += 1;
+        *b += 1;
+    });
+
+    assert_eq!(visited, [(1000, 1), (1001, 10000), (2, 10001)]);
+    assert_eq!(data, [3, 10, 100, 1002, 10002]);
+}
+
+#[test]
+#[should_panic]
+fn for_each_pair_repeated_index_panic()
+{
+    let mut data = [1, 2, 3];
+
+    data.for_each_pair_mut(&[0, 2, 0], std::mem::swap); // Repeated index: should panic
+}
+
+#[cfg(feature = "rayon")]
+mod rayon {
+    use select_indices::prelude::*;
+
+    #[test]
+    fn par_select_pairs_mut()
+    {
+        // Relax a chain of springs: every edge shares a node with its neighbours.
+        let mut nodes: Vec<f64> = vec![0.0, 10.0, 0.0, 10.0, 0.0];
+        let edges: Vec<(usize, usize)> = (0..4).map(|i| (i, i + 1)).collect();
+
+        let pairs = nodes.par_select_pairs_mut(&edges);
+        assert_eq!(pairs.rounds(), 4);
+        pairs.for_each(|(a, b)| {
+            let mid = (*a + *b) / 2.0;
+            *a = mid;
+            *b = mid;
+        });
+
+        // Edges sharing a node run in the order they were given, like a sequential pass.
+        let mut expected: Vec<f64> = vec![0.0, 10.0, 0.0, 10.0, 0.0];
+        for &(a, b) in &edges {
+            let mid = (expected[a] + expected[b]) / 2.0;
+            expected[a] = mid;
+            expected[b] = mid;
+        }
+        assert_eq!(nodes, expected);
+    }
+
+    #[test]
+    fn disjoint_pairs_share_a_round()
+    {
+        let mut nodes = vec![0; 8];
+        let edges = [(0, 1), (2, 3), (4, 5), (6, 7), (1, 2)];
+
+        let pairs = nodes.par_select_pairs_mut(&edges);
+        assert_eq!(pairs.rounds(), 2);
+        pairs.for_each(|(a, b)| {
+            *a += 1;
+            *b += 1;
+        });
+
+        assert_eq!(nodes, [1, 2, 2, 1, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn par_for_each_pair_mut()
+    {
+        use std::sync::atomic::{ AtomicUsize, Ordering };
+
+        // Every element counts how many partners it was paired with.
+        for len in [0, 1, 2, 3, 7, 64, 101] {
+            let mut data: Vec<(usize, usize)> = (0..200).map(|i| (i, 0)).collect();
+            let indices: Vec<usize> = (0..len).map(|i| (i * 37) % 200).collect();
+            let total = AtomicUsize::new(0);
+
+            data.par_for_each_pair_mut(&indices, |a, b| {
+                a.1 += 1;
+                b.1 += 1;
+                total.fetch_add(1, Ordering::Relaxed);
+            });
+
+            assert_eq!(total.into_inner(), len * len.saturating_sub(1) / 2);
+            for (i, &(_, count)) in data.iter().enumerate() {
+                let expected = if indices.contains(&i) { len - 1 } else { 0 };
+                assert_eq!(count, expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn self_pair_panic()
+    {
+        let mut data = vec![0, 1, 2];
+
+        data.par_select_pairs_mut(&[(0, 1), (1, 1)]) // Pair from 1 to itself: should panic
+            .for_each(|(a, b)| std::mem::swap(a, b));
+    }
+}
\ No newline at end of file
@@ -91,4 +91,97 @@ fn repeated_index_from_iter_panic()
 
     data.par_select_with_iter_mut(index_iter)
         .for_each(|x| println!("{x}"));
+}
+
+#[test]
+fn select_owned_indices()
+{
+    let mut data = [
+        0,0,9,0,0,
+        0,0,9,0,0,
+        0,0,9,0,0,
+    ];
+
+    let indices = vec![7,2,12];
+
+    data.par_select_owned_indices_mut(indices).enumerate().for_each(|(i, x)| {
+        *x = i+1;
+    });
+
+    assert_eq!(
+        data,
+        [
+            0,0,2,0,0,
+            0,0,1,0,0,
+            0,0,3,0,0,
+        ]
+    )
+}
+
+#[test]
+#[should_panic]
+fn owned_repeated_index_panic()
+{
+    let mut data = [1,2,3];
+
+    data.par_select_owned_indices_mut(vec![1, 1]) // Repeated index: should panic
+        .for_each(|x| println!("{x}"));
+}
+
+#[test]
+fn replace_indices()
+{
+    let mut data: Vec<String> = (0..6).map(|i| i.to_string()).collect();
+    let values: Vec<String> = vec!["a".into(), "b".into(), "c".into()];
+
+    let old = data.par_replace_indices(&[4, 1, 5], values);
+
+    assert_eq!(old, ["4", "1", "5"]);
+    assert_eq!(data, ["0", "b", "2", "3", "a", "c"]);
+}
+
+#[test]
+fn sort_selected()
+{
+    let mut data: Vec<String> = ["d", "x", "b", "x", "a", "c", "x"]
+        .iter().map(|s| s.to_string()).collect();
+
+    data.par_sort_selected(&[5, 0, 2, 4]);
+    assert_eq!(data, ["b", "x", "c", "x", "d", "a", "x"]);
+
+    data.par_sort_selected_by_key(&[0, 2, 4, 5], |s| std::cmp::Reverse(s.clone()));
+    assert_eq!(data, ["d", "x", "c", "x", "b", "a", "x"]);
+}
+
+#[test]
+fn swap_selections()
+{
+    let mut data: Vec<usize> = (0..100).collect();
+    let a: Vec<usize> = (0..50).collect();
+    let b: Vec<usize> = (50..100).rev().collect();
+
+    data.par_swap_selections(&a, &b);
+
+    assert!(data.iter().copied().eq((0..100).rev()));
+}
+
+#[test]
+#[should_panic]
+fn swap_selections_overlap_panic()
+{
+    let mut data = [0, 1, 2, 3];
+
+    data.par_swap_selections(&[0, 1], &[1, 2]); // 1 is in both selections: should panic
+}
+
+#[test]
+fn swap_between()
+{
+    let mut a = vec![0, 1, 2];
+    let mut b = vec![10, 11, 12];
+
+    a.par_swap_between(&[0, 2], &mut b, &[2, 1]);
+
+    assert_eq!(a, [12, 1, 11]);
+    assert_eq!(b, [10, 2, 0]);
 }
\ No newline at end of file
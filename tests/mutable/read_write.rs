@@ -0,0 +1,88 @@
+use select_indices::prelude::*;
+
+#[test]
+fn select_read_write()
+{
+    let mut data = [3, 5, 0, 0, 0, 7, 0];
+
+    data.select_read_write(&[5, 0, 0, 1], &[4, 3, 2, 6])
+        .for_each(|(input, output)| *output = input + 100);
+
+    assert_eq!(data, [3, 5, 103, 103, 107, 7, 105]);
+}
+
+#[test]
+fn select_read_write_rev()
+{
+    let mut data = [1, 2, 0, 0];
+    let mut order = Vec::new();
+
+    data.select_read_write(&[0, 1], &[2, 3]).rev()
+        .for_each(|(input, output)| {
+            order.push(*input);
+            *output = *input;
+        });
+
+    assert_eq!(order, [2, 1]);
+    assert_eq!(data, [1, 2, 1, 2]);
+}
+
+#[test]
+#[should_panic]
+fn read_is_write_panic()
+{
+    let mut data = [1, 2, 3];
+
+    data.select_read_write(&[0, 1], &[1, 2]) // 1 is read and written: should panic
+        .for_each(|(input, output)| *output = *input);
+}
+
+#[test]
+#[should_panic]
+fn repeated_write_panic()
+{
+    let mut data = [1, 2, 3];
+
+    data.select_read_write(&[0, 0], &[2, 2]) // Repeated write: should panic
+        .for_each(|(input, output)| *output = *input);
+}
+
+#[test]
+#[should_panic]
+fn length_mismatch_panic()
+{
+    let mut data = [1, 2, 3];
+
+    data.select_read_write(&[0], &[1, 2]) // Missing read: should panic
+        .for_each(|(input, output)| *output = *input);
+}
+
+#[cfg(feature = "rayon")]
+mod rayon {
+    use select_indices::prelude::*;
+    use rayon::prelude::*;
+
+    #[test]
+    fn par_select_read_write()
+    {
+        // Scatter the even elements into the odd slots after them.
+        let mut data: Vec<usize> = (0..1000).map(|i| if i % 2 == 0 { i } else { 0 }).collect();
+        let reads: Vec<usize> = (0..500).map(|i| i * 2).collect();
+        let writes: Vec<usize> = (0..500).map(|i| i * 2 + 1).collect();
+
+        data.par_select_read_write(&reads, &writes)
+            .for_each(|(input, output)| *output = input + 1);
+
+        assert_eq!(data, (0..1000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn read_is_write_panic()
+    {
+        let mut data = [1, 2, 3];
+
+        data.par_select_read_write(&[2, 2], &[0, 2]) // 2 is read and written: should panic
+            .for_each(|(input, output)| *output = *input);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,109 @@
+use select_indices::prelude::*;
+
+#[test]
+fn index()
+{
+    let mut data = [0, 10, 20, 30, 40, 50];
+    let mut view = data.selection_mut(&[4, 1, 3]);
+
+    assert_eq!(view.len(), 3);
+    assert_eq!(view[0], 40);
+    assert_eq!(view.get(3), None);
+
+    view[2] += 1;
+    view.iter_mut().for_each(|x| *x += 1);
+    assert!(view.iter().eq(&[41, 11, 32]));
+
+    assert_eq!(data, [0, 11, 20, 32, 41, 50]);
+}
+
+#[test]
+fn swap_and_reverse()
+{
+    let mut data = [0, 1, 2, 3, 4, 5, 6];
+    let mut view = data.selection_mut(&[0, 2, 4, 6]);
+
+    view.swap(0, 1);
+    assert_eq!(view.indices(), [0, 2, 4, 6]);
+    view.reverse();
+
+    assert_eq!(data, [6, 1, 4, 3, 0, 5, 2]);
+}
+
+#[test]
+fn sort()
+{
+    let mut data: Vec<String> = ["d", "x", "b", "x", "a", "c", "x"]
+        .iter().map(|s| s.to_string()).collect();
+    let mut view = data.selection_mut(&[5, 0, 2, 4]);
+
+    view.sort();
+    assert!(view.iter().eq(&["a", "b", "c", "d"]));
+    assert_eq!(view.binary_search(&"c".to_string()), Ok(2));
+    assert_eq!(view.binary_search_by(|x| x.as_str().cmp("bb")), Err(2));
+
+    assert_eq!(data, ["b", "x", "c", "x", "d", "a", "x"]);
+}
+
+#[test]
+fn sort_by_is_stable()
+{
+    let mut data = [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')];
+    let mut view = data.selection_mut(&[3, 2, 1, 0]);
+
+    view.sort_by_key(|&(key, _)| key);
+
+    assert_eq!(data, [(1, 'a'), (1, 'c'), (0, 'b'), (0, 'd')]);
+}
+
+#[test]
+#[should_panic]
+fn repeated_index_panic()
+{
+    let mut data = [1,2,3];
+
+    data.selection_mut(&[0, 2, 0]); // Repeated index: should panic
+}
+
+#[test]
+fn rotate()
+{
+    let mut data = [0, 1, 2, 3, 4, 5, 6];
+    let mut view = data.selection_mut(&[1, 3, 5]);
+
+    view.rotate_left(1);
+    assert!(view.iter().eq(&[3, 5, 1]));
+    view.rotate_right(2);
+    assert!(view.iter().eq(&[5, 1, 3]));
+}
+
+#[test]
+fn sort_selected()
+{
+    let mut data = vec![9, 100, 3, 100, 7, 100, 1];
+
+    data.sort_selected(&[0, 2, 4, 6]);
+    assert_eq!(data, [1, 100, 3, 100, 7, 100, 9]);
+
+    data.sort_selected_by_key(&[6, 4, 2, 0], |&x| x);
+    assert_eq!(data, [9, 100, 7, 100, 3, 100, 1]);
+
+    data.reverse_selected(&[0, 1, 2]);
+    assert_eq!(data, [7, 100, 9, 100, 3, 100, 1]);
+
+    data.rotate_selected(&[0, 2, 4, 6], 1);
+    assert_eq!(data, [9, 100, 3, 100, 1, 100, 7]);
+}
+
+#[test]
+fn nested_select()
+{
+    let mut data = [0, 1, 2, 3, 4, 5, 6, 7];
+    let mut view = data.selection_mut(&[7, 5, 3, 1]);
+
+    let mut narrowed = view.select(&[3, 1]);
+    assert_eq!(narrowed.indices(), [1, 5]);
+    narrowed.swap(0, 1);
+
+    assert!(view.iter().eq(&[7, 1, 3, 5]));
+}
\ No newline at end of file
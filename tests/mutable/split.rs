@@ -0,0 +1,50 @@
+use select_indices::prelude::*;
+
+#[test]
+fn split_selections_mut()
+{
+    let mut data: Vec<usize> = vec![0; 12];
+    let groups: Vec<Vec<usize>> = (0..3).map(|g| (0..12).filter(|i| i % 3 == g).collect()).collect();
+
+    let selections = data.split_selections_mut(&groups);
+    assert_eq!(selections.len(), 3);
+
+    std::thread::scope(|scope| {
+        for (g, selection) in selections.into_iter().enumerate() {
+            scope.spawn(move || selection.for_each(|x| *x = g + 1));
+        }
+    });
+
+    assert_eq!(data, [1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3]);
+}
+
+#[test]
+fn split_selections_slices()
+{
+    let mut data = [0, 10, 20, 30];
+    let groups: [&[usize]; 2] = [&[3], &[]];
+
+    let mut selections = data.split_selections_mut(&groups);
+    assert!(selections[1].next().is_none());
+    *selections[0].next().unwrap() += 1;
+
+    assert_eq!(data, [0, 10, 20, 31]);
+}
+
+#[test]
+#[should_panic]
+fn overlapping_groups_panic()
+{
+    let mut data = [1, 2, 3, 4];
+
+    data.split_selections_mut(&[[0, 1], [2, 1]]); // 1 is in both groups: should panic
+}
+
+#[test]
+#[should_panic]
+fn repeated_index_panic()
+{
+    let mut data = [1, 2, 3, 4];
+
+    data.split_selections_mut(&[[0, 0]]); // Repeated index: should panic
+}
\ No newline at end of file
@@ -0,0 +1,154 @@
+use select_indices::prelude::*;
+use std::{
+    collections::{ BTreeSet, HashSet, BTreeMap },
+    hash::{ BuildHasher, Hasher },
+    cell::Cell,
+};
+
+#[test]
+fn select_unique_iter_mut()
+{
+    let mut data = [0; 10];
+
+    data.select_unique_iter_mut(2..5).for_each(|x| *x += 1);
+    data.select_unique_iter_mut((0..=9).rev().step_by(3)).for_each(|x| *x += 10);
+    data.select_unique_iter_mut((0..10).filter(|i| i % 4 == 0).skip(1).take(1)).for_each(|x| *x += 100);
+
+    assert_eq!(data, [10, 0, 1, 11, 101, 0, 10, 0, 0, 10]);
+}
+
+#[test]
+fn select_unique_iter_mut_collections()
+{
+    let mut data = [0; 6];
+    let btree = BTreeSet::from([5, 0, 2]);
+    let hash = HashSet::from([1, 2, 3]);
+    let map = BTreeMap::from([(4, "four"), (0, "zero")]);
+
+    data.select_unique_iter_mut(btree.iter().copied()).for_each(|x| *x += 1);
+    data.select_unique_iter_mut(btree.range(1..).cloned()).for_each(|x| *x += 10);
+    data.select_unique_iter_mut(hash.set_indices()).for_each(|x| *x += 100);
+    data.select_unique_iter_mut(map.keys().copied()).for_each(|x| *x += 1000);
+
+    assert_eq!(data, [1001, 100, 111, 100, 1000, 11]);
+}
+
+/// Hashes every key differently, so a set built with it can hold the same key twice.
+#[derive(Default)]
+struct UnstableState(Cell<u64>);
+
+struct UnstableHasher(u64);
+
+impl BuildHasher for UnstableState
+{
+    type Hasher = UnstableHasher;
+
+    fn build_hasher(&self) -> UnstableHasher {
+        self.0.set(self.0.get() + 1);
+        UnstableHasher(self.0.get().wrapping_mul(0x9e37_79b9_7f4a_7c15))
+    }
+}
+
+impl Hasher for UnstableHasher
+{
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, _bytes: &[u8]) {}
+}
+
+#[test]
+#[should_panic]
+fn unstable_hash_set_repeated_index_panic()
+{
+    let mut data = [1, 2, 3];
+    let mut set = HashSet::with_hasher(UnstableState::default());
+    set.insert(1);
+    set.insert(1);
+    assert_eq!(set.len(), 2);
+
+    data.select_with_iter_mut(set.iter().copied()) // Repeated index: should panic
+        .for_each(|x| *x += 1);
+}
+
+#[test]
+fn sorted_dedup()
+{
+    let mut data = [0; 5];
+
+    let visited: Vec<usize> = SortedDedup::new([0, 0, 1, 3, 3, 3, 4]).collect();
+    data.select_unique_iter_mut(SortedDedup::new(visited.clone())).for_each(|x| *x += 1);
+
+    assert_eq!(visited, [0, 1, 3, 4]);
+    assert_eq!(data, [1, 1, 0, 1, 1]);
+}
+
+#[test]
+#[should_panic]
+fn sorted_dedup_unsorted_panic()
+{
+    let mut data = [0; 5];
+
+    data.select_unique_iter_mut(SortedDedup::new(vec![1, 3, 1])) // Unsorted: should panic
+        .for_each(|x| *x += 1);
+}
+
+#[test]
+fn select_set_mut()
+{
+    let mut data = [0; 8];
+    let btree = BTreeSet::from([6, 2, 4]);
+    let hash = HashSet::from([1, 2, 7]);
+    let pattern = IndexPattern::every_nth(3, 8);
+
+    let order: Vec<usize> = data.select_set_mut(&btree).enumerate().map(|(i, x)| { *x += 1; i }).collect();
+    data.select_set_mut(&hash).for_each(|x| *x += 10);
+    data.select_set_mut(&pattern).for_each(|x| *x += 100);
+
+    assert_eq!(order, [0, 1, 2]);
+    assert_eq!(data, [100, 10, 11, 100, 1, 0, 101, 10]);
+}
+
+#[cfg(feature = "rayon")]
+mod rayon {
+    use select_indices::prelude::*;
+    use rayon::prelude::*;
+    use std::collections::{ BTreeSet, HashSet };
+
+    #[test]
+    fn par_select_unique_iter_mut()
+    {
+        let mut data = vec![0; 1000];
+        let set: BTreeSet<usize> = (0..1000).filter(|i| i % 7 == 0).collect();
+
+        data.par_select_unique_iter_mut((0..1000).into_par_iter().step_by(2)).for_each(|x| *x += 1);
+        data.par_select_unique_iter_mut(set.par_iter().copied()).for_each(|x| *x += 10);
+
+        for (i, x) in data.iter().enumerate() {
+            let expected = if i % 2 == 0 { 1 } else { 0 } + if i % 7 == 0 { 10 } else { 0 };
+            assert_eq!(*x, expected);
+        }
+    }
+
+    #[test]
+    fn par_select_set_mut()
+    {
+        let mut data = vec![0; 1000];
+        let btree: BTreeSet<usize> = (0..1000).step_by(5).collect();
+        let hash: HashSet<usize> = (0..1000).step_by(2).collect();
+        let pattern = IndexPattern::every_nth(3, 1000);
+
+        data.par_select_set_mut(&btree).for_each(|x| *x += 1);
+        data.par_select_set_mut(&hash).for_each(|x| *x += 10);
+        data.par_select_set_mut(&pattern).for_each(|x| *x += 100);
+
+        for (i, x) in data.iter().enumerate() {
+            let expected = [(5, 1), (2, 10), (3, 100)].iter()
+                .filter(|&&(n, _)| i % n == 0)
+                .map(|&(_, add)| add)
+                .sum::<i32>();
+            assert_eq!(*x, expected);
+        }
+    }
+}
\ No newline at end of file
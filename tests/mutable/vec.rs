@@ -0,0 +1,111 @@
+use select_indices::prelude::*;
+
+#[test]
+fn extract_indices()
+{
+    let mut data = vec![0, 10, 20, 30, 40, 50, 60];
+
+    let extracted = data.extract_indices(&[5, 1, 3]);
+
+    assert_eq!(extracted, [50, 10, 30]);
+    assert_eq!(data, [0, 20, 40, 60]);
+}
+
+#[test]
+fn extract_indices_owned()
+{
+    let mut data: Vec<String> = (0..5).map(|i| i.to_string()).collect();
+
+    let extracted = data.extract_indices(&[4, 0]);
+
+    assert_eq!(extracted, ["4", "0"]);
+    assert_eq!(data, ["1", "2", "3"]);
+}
+
+#[test]
+fn swap_remove_indices()
+{
+    let mut data = vec![0, 10, 20, 30, 40, 50, 60];
+
+    let removed = data.swap_remove_indices(&[1, 6, 2]);
+
+    assert_eq!(removed, [10, 60, 20]);
+    data.sort_unstable();
+    assert_eq!(data, [0, 30, 40, 50]);
+}
+
+#[test]
+#[should_panic]
+fn extract_repeated_index_panic()
+{
+    let mut data = vec![1,2,3];
+
+    data.extract_indices(&[1,1]); // Repeated index: should panic
+}
+
+#[test]
+#[should_panic]
+fn swap_remove_out_of_range_panic()
+{
+    let mut data = vec![1,2,3];
+
+    data.swap_remove_indices(&[0,3]); // 3 is out of bounds: should panic
+}
+
+#[test]
+fn remove_indices()
+{
+    let mut data = vec![0, 10, 20, 30, 40, 50, 60];
+
+    data.remove_indices(&[6, 0, 3]);
+
+    assert_eq!(data, [10, 20, 40, 50]);
+}
+
+#[test]
+fn retain_indices()
+{
+    let mut data = vec![0, 10, 20, 30, 40, 50, 60];
+
+    data.retain_indices(&[6, 0, 3]);
+
+    assert_eq!(data, [0, 30, 60]);
+}
+
+#[test]
+fn insert_at_indices()
+{
+    let mut data = vec![10, 20, 30];
+
+    data.insert_at_indices(vec![(5, 5), (0, 0), (2, 2)]);
+
+    assert_eq!(data, [0, 10, 2, 20, 30, 5]);
+}
+
+#[test]
+fn insert_at_indices_owned()
+{
+    let mut data: Vec<String> = vec!["b".into(), "d".into()];
+
+    data.insert_at_indices(vec![(2, "c".into()), (0, "a".into())]);
+
+    assert_eq!(data, ["a", "b", "c", "d"]);
+}
+
+#[test]
+#[should_panic]
+fn insert_out_of_range_panic()
+{
+    let mut data = vec![1,2,3];
+
+    data.insert_at_indices(vec![(0, 0), (5, 5)]); // 5 is out of bounds: should panic
+}
+
+#[test]
+#[should_panic]
+fn retain_repeated_index_panic()
+{
+    let mut data = vec![1,2,3];
+
+    data.retain_indices(&[2,2]); // Repeated index: should panic
+}
\ No newline at end of file
@@ -0,0 +1,81 @@
+use select_indices::prelude::*;
+
+#[test]
+fn zip_mut()
+{
+    let mut positions = vec![[0.0, 0.0]; 5];
+    let mut velocities = vec![[1.0, 2.0]; 5];
+    let masses = [1.0, 2.0, 3.0, 4.0, 5.0];
+
+    select_zip_mut((&mut positions, &mut velocities, &masses), &[4, 0, 2])
+        .for_each(|(position, velocity, mass)| {
+            velocity[0] /= mass;
+            position[0] += velocity[0];
+            position[1] += velocity[1];
+        });
+
+    assert_eq!(positions, [[1.0, 2.0], [0.0, 0.0], [1.0 / 3.0, 2.0], [0.0, 0.0], [0.2, 2.0]]);
+    assert_eq!(velocities[3], [1.0, 2.0]);
+}
+
+#[test]
+fn zip_eight()
+{
+    let (mut a, mut b, mut c, mut d) = ([0; 3], [0; 3], [0; 3], [0; 3]);
+    let (e, f, g, h) = ([1; 3], [2; 3], [3; 3], [4; 3]);
+
+    let iter = select_zip_mut((&mut a, &mut b, &mut c, &mut d, &e, &f, &g, &h), &[2, 0]);
+    assert_eq!(iter.len(), 2);
+    iter.rev().enumerate().for_each(|(i, (a, b, c, d, e, f, g, h))| {
+        *a = i;
+        *b = e + f;
+        *c = g + h;
+        *d = e + f + g + h;
+    });
+
+    assert_eq!(a, [0, 0, 1]);
+    assert_eq!(b, [3, 0, 3]);
+    assert_eq!(c, [7, 0, 7]);
+    assert_eq!(d, [10, 0, 10]);
+}
+
+#[test]
+#[should_panic]
+fn repeated_index_panic()
+{
+    let mut a = [1, 2, 3];
+    let b = [1, 2, 3];
+
+    select_zip_mut((&mut a, &b), &[1, 1]) // Repeated index: should panic
+        .for_each(|(a, b)| *a += b);
+}
+
+#[test]
+#[should_panic]
+fn out_of_range_panic()
+{
+    let mut a = [1, 2, 3, 4];
+    let b = [1, 2, 3];
+
+    select_zip_mut((&mut a, &b), &[0, 3]) // 3 is out of bounds for b: should panic
+        .for_each(|(a, b)| *a += b);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_zip_mut()
+{
+    use rayon::prelude::*;
+
+    let mut positions: Vec<f32> = vec![0.0; 100];
+    let velocities: Vec<f32> = (0..100).map(|i| i as f32).collect();
+    let indices: Vec<usize> = (0..100).step_by(3).collect();
+
+    let iter = par_select_zip_mut((&mut positions, &velocities), &indices);
+    assert_eq!(iter.len(), indices.len());
+    iter.for_each(|(position, velocity)| *position += velocity);
+
+    positions.iter().enumerate().for_each(|(i, &position)| {
+        assert_eq!(position, if i % 3 == 0 { i as f32 } else { 0.0 });
+    });
+}
\ No newline at end of file
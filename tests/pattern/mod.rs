@@ -0,0 +1,88 @@
+use select_indices::prelude::*;
+
+#[test]
+fn shapes()
+{
+    assert_eq!(IndexPattern::range(2..5).as_slice(), [2, 3, 4]);
+    assert_eq!(IndexPattern::strided(1..10, 3).as_slice(), [1, 4, 7]);
+    assert_eq!(IndexPattern::every_nth(4, 10).as_slice(), [0, 4, 8]);
+    assert_eq!(IndexPattern::block(5, 1..3, 2..4).as_slice(), [7, 8, 12, 13]);
+    assert_eq!(IndexPattern::diagonal(3, 4).as_slice(), [0, 4, 8]);
+    assert_eq!(IndexPattern::from_indices(vec![5, 1, 5, 3, 1]).as_slice(), [1, 3, 5]);
+    assert!(IndexPattern::empty().is_empty());
+}
+
+#[test]
+fn set_algebra()
+{
+    let evens = IndexPattern::every_nth(2, 10);
+    let thirds = IndexPattern::every_nth(3, 10);
+
+    assert_eq!(evens.union(&thirds).as_slice(), [0, 2, 3, 4, 6, 8, 9]);
+    assert_eq!(evens.intersection(&thirds).as_slice(), [0, 6]);
+    assert_eq!(evens.difference(&thirds).as_slice(), [2, 4, 8]);
+    assert_eq!(evens.symmetric_difference(&thirds).as_slice(), [2, 3, 4, 8, 9]);
+    assert_eq!(evens.union(&IndexPattern::empty()), evens);
+    assert!(evens.contains(8));
+    assert!(!evens.contains(9));
+}
+
+#[test]
+fn select_pattern()
+{
+    let mut data = [0; 9];
+    let diagonal = IndexPattern::diagonal(3, 3);
+    let middle_row = IndexPattern::block(3, 1..2, 0..3);
+
+    data.select_unique_iter_mut(&diagonal).for_each(|x| *x += 1);
+    data.select_unique_iter_mut(middle_row.difference(&diagonal)).for_each(|x| *x += 10);
+    data.select_unique_iter_mut(diagonal.iter().rev()).enumerate().for_each(|(i, x)| *x += 100 * i);
+
+    assert_eq!(data, [
+        201, 0, 0,
+        10, 101, 10,
+        0, 0, 1,
+    ]);
+}
+
+#[test]
+#[should_panic]
+fn block_past_row_panic()
+{
+    IndexPattern::block(4, 0..2, 2..5); // Column 4 is past the row: should panic
+}
+
+#[test]
+#[should_panic]
+fn block_overflow_panic()
+{
+    IndexPattern::block(1 << (usize::BITS - 1), 0..3, 0..1); // Row 2 starts past usize::MAX: should panic
+}
+
+#[test]
+#[should_panic]
+fn diagonal_overflow_panic()
+{
+    IndexPattern::diagonal(usize::MAX, 2); // The second index is past usize::MAX: should panic
+}
+
+#[cfg(feature = "rayon")]
+mod rayon {
+    use select_indices::prelude::*;
+    use rayon::prelude::*;
+
+    #[test]
+    fn par_select_pattern()
+    {
+        let mut data = vec![0; 100 * 100];
+        let pattern = IndexPattern::block(100, 10..90, 10..90)
+            .symmetric_difference(&IndexPattern::diagonal(100, 100));
+
+        data.par_select_unique_iter_mut(&pattern).for_each(|x| *x += 1);
+
+        assert_eq!(pattern.par_iter().count(), 80 * 80 - 80 + 20);
+        for (i, x) in data.iter().enumerate() {
+            assert_eq!(*x, pattern.contains(i) as i32);
+        }
+    }
+}
\ No newline at end of file
@@ -1,3 +1,7 @@
 mod immutable;
 
-mod mutable;
\ No newline at end of file
+mod mutable;
+
+mod gather;
+
+mod pattern;
\ No newline at end of file